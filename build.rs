@@ -0,0 +1,16 @@
+fn main() {
+    #[cfg(feature = "ffi")]
+    {
+        println!("cargo::rerun-if-changed=src/ffi.rs");
+        println!("cargo::rerun-if-changed=cbindgen.toml");
+
+        let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        let out_dir = std::env::var("OUT_DIR").unwrap();
+
+        // the committed `include/malie.h` is checked against this copy by a
+        // test, so that building never writes to the source tree
+        cbindgen::generate(&crate_dir)
+            .expect("failed to generate C bindings")
+            .write_to_file(std::path::Path::new(&out_dir).join("malie.h"));
+    }
+}
@@ -0,0 +1,218 @@
+#ifndef MALIE_H
+#define MALIE_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A single card, borrowed from a [`MalieCards`].
+ */
+typedef struct MalieCard MalieCard;
+
+/**
+ * An iterator over the cards in a [`MalieCards`].
+ */
+typedef struct MalieCardIter MalieCardIter;
+
+/**
+ * An owned collection of cards.
+ */
+typedef struct MalieCards MalieCards;
+
+/**
+ * A borrowed UTF-8 string, which is *not* NUL-terminated.
+ *
+ * Absent values are represented by a null `ptr` and a `len` of 0.
+ */
+typedef struct MalieStr {
+  const uint8_t *ptr;
+  size_t len;
+} MalieStr;
+
+/**
+ * Parse the contents of an export file.
+ *
+ * Returns null if `json` is not a valid export, and the reason is then
+ * available from [`malie_last_error`].
+ *
+ * # Safety
+ *
+ * `json` must point to `len` readable bytes.
+ */
+struct MalieCards *malie_cards_parse(const uint8_t *json, size_t len);
+
+/**
+ * Why the last call to [`malie_cards_parse`] on the current thread returned
+ * null, with the line and column the error occurred at, or an absent string
+ * if it succeeded.
+ *
+ * The string is only valid until the next call to [`malie_cards_parse`] on
+ * the same thread, and must not be freed.
+ */
+struct MalieStr malie_last_error(void);
+
+/**
+ * Free a collection returned by [`malie_cards_parse`].
+ */
+void malie_cards_free(struct MalieCards *cards);
+
+/**
+ * The number of cards in the collection, or 0 if `cards` is null.
+ *
+ * # Safety
+ *
+ * `cards` must be null or a live collection.
+ */
+size_t malie_cards_len(const struct MalieCards *cards);
+
+/**
+ * The card at `index`, or null if it is out of bounds or `cards` is null.
+ *
+ * # Safety
+ *
+ * `cards` must be null or a live collection.
+ */
+const struct MalieCard *malie_cards_get(const struct MalieCards *cards, size_t index);
+
+/**
+ * Iterate over the cards in the collection, or null if `cards` is null.
+ *
+ * The iterator must be released with [`malie_card_iter_free`], before the
+ * collection is.
+ *
+ * # Safety
+ *
+ * `cards` must be null or a live collection.
+ */
+struct MalieCardIter *malie_cards_iter(const struct MalieCards *cards);
+
+/**
+ * The next card, or null once every card has been returned or if `iter` is
+ * null.
+ *
+ * # Safety
+ *
+ * `iter` must be null or a live iterator.
+ */
+const struct MalieCard *malie_card_iter_next(struct MalieCardIter *iter);
+
+/**
+ * Free an iterator returned by [`malie_cards_iter`].
+ */
+void malie_card_iter_free(struct MalieCardIter *iter);
+
+/**
+ * The `card_type` of the card, i.e. `POKEMON`.
+ *
+ * # Safety
+ *
+ * `card` must be null or a card of a live collection.
+ */
+struct MalieStr malie_card_type(const struct MalieCard *card);
+
+/**
+ * The `subtype` of the card, i.e. `ITEM`, if it has one.
+ *
+ * # Safety
+ *
+ * `card` must be null or a card of a live collection.
+ */
+struct MalieStr malie_card_subtype(const struct MalieCard *card);
+
+/**
+ * The name of the card.
+ *
+ * # Safety
+ *
+ * `card` must be null or a card of a live collection.
+ */
+struct MalieStr malie_card_name(const struct MalieCard *card);
+
+/**
+ * The language of the card, i.e. `en-US`.
+ *
+ * # Safety
+ *
+ * `card` must be null or a card of a live collection.
+ */
+struct MalieStr malie_card_lang(const struct MalieCard *card);
+
+/**
+ * The set icon of the card, i.e. `SVI`.
+ *
+ * # Safety
+ *
+ * `card` must be null or a card of a live collection.
+ */
+struct MalieStr malie_card_set_icon(const struct MalieCard *card);
+
+/**
+ * The full collector number of the card, i.e. `244/198`.
+ *
+ * # Safety
+ *
+ * `card` must be null or a card of a live collection.
+ */
+struct MalieStr malie_card_collector_number(const struct MalieCard *card);
+
+/**
+ * The rarity designation of the card, i.e. `DOUBLE_RARE`, if it has one.
+ *
+ * # Safety
+ *
+ * `card` must be null or a card of a live collection.
+ */
+struct MalieStr malie_card_rarity(const struct MalieCard *card);
+
+/**
+ * The regulation mark of the card, i.e. `G`, if it has one.
+ *
+ * # Safety
+ *
+ * `card` must be null or a card of a live collection.
+ */
+struct MalieStr malie_card_regulation_mark(const struct MalieCard *card);
+
+/**
+ * The HP of the card, or 0 if it has none.
+ *
+ * # Safety
+ *
+ * `card` must be null or a card of a live collection.
+ */
+uint16_t malie_card_hp(const struct MalieCard *card);
+
+/**
+ * The number of energy types the card has.
+ *
+ * # Safety
+ *
+ * `card` must be null or a card of a live collection.
+ */
+size_t malie_card_energy_types_len(const struct MalieCard *card);
+
+/**
+ * The energy type at `index`, i.e. `GRASS`, or a null string if it is out
+ * of bounds.
+ *
+ * # Safety
+ *
+ * `card` must be null or a card of a live collection.
+ */
+struct MalieStr malie_card_energy_type(const struct MalieCard *card, size_t index);
+
+/**
+ * The TCGL `cardID` of the card, i.e. `SVI_244`.
+ *
+ * # Safety
+ *
+ * `card` must be null or a card of a live collection.
+ */
+struct MalieStr malie_card_id(const struct MalieCard *card);
+
+#endif  /* MALIE_H */
@@ -0,0 +1,423 @@
+//! Columnar export of cards to [Apache Arrow] record batches, and optionally
+//! to Parquet.
+//!
+//! Enum values are written as their serialized names (i.e. `"SV_ULTRA"`), so
+//! that the columns can be queried with the same values as the JSON export.
+//!
+//! [Apache Arrow]: https://arrow.apache.org
+
+use alloc::{boxed::Box, sync::Arc, vec, vec::Vec};
+use core::num::NonZeroU16;
+
+use arrow_array::{
+    ArrayRef, RecordBatch,
+    builder::{
+        ArrayBuilder, ListBuilder, StringBuilder, StructBuilder, TimestampSecondBuilder,
+        UInt16Builder, UInt32Builder,
+    },
+};
+use arrow_schema::{ArrowError, DataType, Field, Fields, Schema, SchemaRef, TimeUnit};
+
+use crate::{Card, Text, serde_name};
+
+const TIMEZONE: &str = "+00:00";
+
+/// The schema of the record batches produced by [`record_batch`].
+#[must_use]
+pub fn schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("card_type", DataType::Utf8, false),
+        Field::new("subtype", DataType::Utf8, true),
+        Field::new("name", DataType::Utf8, false),
+        Field::new("lang", DataType::Utf8, false),
+        Field::new("set_icon", DataType::Utf8, false),
+        Field::new("collector_number", DataType::Utf8, false),
+        Field::new("collector_number_numeric", DataType::UInt16, false),
+        Field::new("rarity", DataType::Utf8, true),
+        Field::new("foil_type", DataType::Utf8, true),
+        Field::new("foil_mask", DataType::Utf8, true),
+        Field::new("regulation_mark", DataType::Utf8, true),
+        Field::new("hp", DataType::UInt16, true),
+        Field::new("archetype_id", DataType::UInt32, false),
+        Field::new("card_id", DataType::Utf8, false),
+        Field::new(
+            "reldate",
+            DataType::Timestamp(TimeUnit::Second, Some(TIMEZONE.into())),
+            false,
+        ),
+        Field::new("types", DataType::List(string_item()), false),
+        Field::new("tags", DataType::List(string_item()), false),
+        Field::new(
+            "text",
+            DataType::List(Arc::new(Field::new(
+                "item",
+                DataType::Struct(text_fields()),
+                false,
+            ))),
+            false,
+        ),
+    ]))
+}
+
+fn string_item() -> Arc<Field> {
+    Arc::new(Field::new("item", DataType::Utf8, false))
+}
+
+fn text_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("kind", DataType::Utf8, false),
+        Field::new("name", DataType::Utf8, true),
+        Field::new("text", DataType::Utf8, true),
+        Field::new("cost", DataType::List(string_item()), true),
+        Field::new("damage", DataType::UInt16, true),
+        Field::new("damage_suffix", DataType::Utf8, true),
+    ])
+}
+
+/// Convert `cards` into a single [`RecordBatch`] with the schema returned by
+/// [`schema`].
+///
+/// # Errors
+///
+/// Returns an error if the columns could not be assembled into a record
+/// batch.
+pub fn record_batch(cards: &[Card<'_>]) -> Result<RecordBatch, ArrowError> {
+    let mut builders = Builders::new(cards.len());
+
+    for card in cards {
+        builders.append(card);
+    }
+
+    RecordBatch::try_new(schema(), builders.finish())
+}
+
+/// Write `cards` as a Parquet file to `writer`, returning the writer once the
+/// file has been fully written.
+///
+/// # Errors
+///
+/// Returns an error if building the record batch or writing the file fails.
+#[cfg(feature = "parquet")]
+pub fn write_parquet<W: std::io::Write + Send>(
+    writer: W,
+    cards: &[Card<'_>],
+) -> Result<W, parquet::errors::ParquetError> {
+    let mut writer = parquet::arrow::ArrowWriter::try_new(writer, schema(), None)?;
+    writer.write(&record_batch(cards)?)?;
+    writer.into_inner()
+}
+
+struct Builders {
+    card_type: StringBuilder,
+    subtype: StringBuilder,
+    name: StringBuilder,
+    lang: StringBuilder,
+    set_icon: StringBuilder,
+    collector_number: StringBuilder,
+    collector_number_numeric: UInt16Builder,
+    rarity: StringBuilder,
+    foil_type: StringBuilder,
+    foil_mask: StringBuilder,
+    regulation_mark: StringBuilder,
+    hp: UInt16Builder,
+    archetype_id: UInt32Builder,
+    card_id: StringBuilder,
+    reldate: TimestampSecondBuilder,
+    types: ListBuilder<StringBuilder>,
+    tags: ListBuilder<StringBuilder>,
+    text: ListBuilder<StructBuilder>,
+}
+
+impl Builders {
+    fn new(capacity: usize) -> Self {
+        let string_list = || ListBuilder::new(StringBuilder::new()).with_field(string_item());
+
+        let text = StructBuilder::new(
+            text_fields(),
+            vec![
+                Box::new(StringBuilder::new()) as Box<dyn ArrayBuilder>,
+                Box::new(StringBuilder::new()),
+                Box::new(StringBuilder::new()),
+                Box::new(string_list()),
+                Box::new(UInt16Builder::new()),
+                Box::new(StringBuilder::new()),
+            ],
+        );
+
+        Self {
+            card_type: StringBuilder::with_capacity(capacity, 0),
+            subtype: StringBuilder::with_capacity(capacity, 0),
+            name: StringBuilder::with_capacity(capacity, 0),
+            lang: StringBuilder::with_capacity(capacity, 0),
+            set_icon: StringBuilder::with_capacity(capacity, 0),
+            collector_number: StringBuilder::with_capacity(capacity, 0),
+            collector_number_numeric: UInt16Builder::with_capacity(capacity),
+            rarity: StringBuilder::with_capacity(capacity, 0),
+            foil_type: StringBuilder::with_capacity(capacity, 0),
+            foil_mask: StringBuilder::with_capacity(capacity, 0),
+            regulation_mark: StringBuilder::with_capacity(capacity, 0),
+            hp: UInt16Builder::with_capacity(capacity),
+            archetype_id: UInt32Builder::with_capacity(capacity),
+            card_id: StringBuilder::with_capacity(capacity, 0),
+            reldate: TimestampSecondBuilder::with_capacity(capacity).with_timezone(TIMEZONE),
+            types: string_list(),
+            tags: string_list(),
+            text: ListBuilder::new(text).with_field(Arc::new(Field::new(
+                "item",
+                DataType::Struct(text_fields()),
+                false,
+            ))),
+        }
+    }
+
+    fn append(&mut self, card: &Card<'_>) {
+        let (card_type, subtype) = card.type_tags();
+
+        self.card_type.append_value(card_type);
+        self.subtype.append_option(subtype);
+        self.name.append_value(card.name());
+        self.lang.append_value(serde_name::variant(card.lang()));
+        self.set_icon.append_value(card.set_icon());
+        self.collector_number
+            .append_value(&card.collector_number().full);
+        self.collector_number_numeric
+            .append_value(card.collector_number().numeric.get());
+        self.rarity.append_option(
+            card.rarity()
+                .map(|rarity| serde_name::variant(&rarity.designation)),
+        );
+        self.foil_type
+            .append_option(card.foil().map(|foil| serde_name::variant(&foil.ty)));
+        self.foil_mask
+            .append_option(card.foil().map(|foil| serde_name::variant(&foil.mask)));
+        self.regulation_mark
+            .append_option(card.regulation_mark().map(serde_name::variant));
+        self.hp.append_option(card.hp().map(NonZeroU16::get));
+        self.archetype_id
+            .append_value(card.tcgl().archetype_id.get());
+        self.card_id.append_value(card.tcgl().card_id.as_str());
+        self.reldate
+            .append_value(card.tcgl().reldate.unix_timestamp());
+
+        self.types
+            .append_value(card.types().iter().map(|ty| Some(serde_name::variant(ty))));
+        self.tags
+            .append_value(card.tags().iter().map(|tag| Some(serde_name::variant(tag))));
+
+        for text in card.text() {
+            append_text(self.text.values(), text);
+        }
+        self.text.append(true);
+    }
+
+    fn finish(mut self) -> Vec<ArrayRef> {
+        vec![
+            Arc::new(self.card_type.finish()),
+            Arc::new(self.subtype.finish()),
+            Arc::new(self.name.finish()),
+            Arc::new(self.lang.finish()),
+            Arc::new(self.set_icon.finish()),
+            Arc::new(self.collector_number.finish()),
+            Arc::new(self.collector_number_numeric.finish()),
+            Arc::new(self.rarity.finish()),
+            Arc::new(self.foil_type.finish()),
+            Arc::new(self.foil_mask.finish()),
+            Arc::new(self.regulation_mark.finish()),
+            Arc::new(self.hp.finish()),
+            Arc::new(self.archetype_id.finish()),
+            Arc::new(self.card_id.finish()),
+            Arc::new(self.reldate.finish()),
+            Arc::new(self.types.finish()),
+            Arc::new(self.tags.finish()),
+            Arc::new(self.text.finish()),
+        ]
+    }
+}
+
+fn append_text(builder: &mut StructBuilder, text: &Text<'_>) {
+    let (kind, name, body, attack) = match text {
+        Text::Attack(attack) => (
+            "ATTACK",
+            Some(&*attack.name),
+            attack.text.as_deref(),
+            Some(attack),
+        ),
+        Text::Ability(ability) => ("ABILITY", Some(&*ability.name), Some(&*ability.text), None),
+        Text::RuleBox(rule_box) => (
+            "RULE_BOX",
+            Some(&*rule_box.name),
+            Some(&*rule_box.text),
+            None,
+        ),
+        Text::Effect(effect) => ("EFFECT", Some(&*effect.name), Some(&*effect.text), None),
+        Text::TextBox(text_box) => (
+            "TEXT_BOX",
+            text_box.name.as_deref(),
+            Some(&*text_box.text),
+            None,
+        ),
+        Text::Reminder(reminder) => ("REMINDER", None, Some(&*reminder.text), None),
+    };
+
+    string_field(builder, 0).append_value(kind);
+    string_field(builder, 1).append_option(name);
+    string_field(builder, 2).append_option(body);
+
+    let cost = builder
+        .field_builder::<ListBuilder<StringBuilder>>(3)
+        .expect("field 3 is the cost list");
+    match attack {
+        Some(attack) => cost.append_value(
+            attack
+                .cost
+                .iter()
+                .map(|cost| Some(serde_name::variant(cost))),
+        ),
+        None => cost.append_null(),
+    }
+
+    let damage = attack.and_then(|attack| attack.damage.as_ref());
+    builder
+        .field_builder::<UInt16Builder>(4)
+        .expect("field 4 is the damage amount")
+        .append_option(damage.map(|damage| damage.amount));
+    string_field(builder, 5).append_option(
+        damage
+            .and_then(|damage| damage.suffix.as_ref())
+            .map(serde_name::variant),
+    );
+
+    builder.append(true);
+}
+
+fn string_field(builder: &mut StructBuilder, i: usize) -> &mut StringBuilder {
+    builder
+        .field_builder::<StringBuilder>(i)
+        .expect("field is a string")
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn parquet_roundtrip() {
+    use arrow_array::{Array, ListArray, StringArray, StructArray};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    let cards = [crate::fixture::Fixture::item().card()];
+
+    let parquet = write_parquet(Vec::new(), &cards).unwrap();
+
+    let batch = ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::from(parquet))
+        .unwrap()
+        .build()
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(batch.schema(), schema());
+    assert_eq!(batch, record_batch(&cards).unwrap());
+
+    let text = batch
+        .column_by_name("text")
+        .unwrap()
+        .as_any()
+        .downcast_ref::<ListArray>()
+        .unwrap()
+        .value(0);
+    let kinds = text
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap()
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap()
+        .clone();
+
+    assert_eq!(kinds, StringArray::from(vec!["EFFECT", "REMINDER"]));
+}
+
+#[test]
+fn columns() {
+    use arrow_array::{
+        Array, StringArray, TimestampSecondArray, UInt16Array, UInt32Array, cast::AsArray,
+        types::UInt16Type,
+    };
+
+    use crate::fixture::Fixture;
+
+    let cards = [
+        Fixture::pokemon().card(),
+        Fixture::item().card(),
+        Fixture::basic_energy().card(),
+    ];
+    let batch = record_batch(&cards).unwrap();
+
+    assert_eq!(batch.schema(), schema());
+    assert_eq!(batch.num_rows(), 3);
+
+    let column = |name: &str| batch.column_by_name(name).unwrap();
+    assert_eq!(
+        column("card_type").as_string::<i32>(),
+        &StringArray::from(vec!["POKEMON", "TRAINER", "ENERGY"])
+    );
+    assert_eq!(
+        column("subtype").as_string::<i32>(),
+        &StringArray::from(vec![None, Some("ITEM"), Some("BASIC")])
+    );
+    assert_eq!(
+        column("card_id").as_string::<i32>(),
+        &StringArray::from(vec!["SVI_81", "SVI_181", "SVE_1"])
+    );
+    assert_eq!(
+        column("collector_number_numeric").as_primitive::<UInt16Type>(),
+        &UInt16Array::from(vec![81, 181, 1])
+    );
+    assert_eq!(
+        column("hp").as_primitive::<UInt16Type>(),
+        &UInt16Array::from(vec![Some(220), None, None])
+    );
+    assert_eq!(
+        column("regulation_mark").as_string::<i32>(),
+        &StringArray::from(vec![Some("G"), Some("G"), None])
+    );
+    assert_eq!(
+        column("archetype_id")
+            .as_any()
+            .downcast_ref::<UInt32Array>()
+            .unwrap(),
+        &UInt32Array::from(vec![0x0001_beef, 0x181, 0x1])
+    );
+    assert_eq!(
+        column("reldate")
+            .as_any()
+            .downcast_ref::<TimestampSecondArray>()
+            .unwrap()
+            .value(0),
+        1_680_220_800
+    );
+
+    let types = column("types").as_list::<i32>();
+    assert_eq!(
+        types.value(0).as_string::<i32>(),
+        &StringArray::from(vec!["LIGHTNING"])
+    );
+    assert_eq!(types.value(1).len(), 0);
+
+    // the attack of Miraidon ex, then its rule box
+    let text = column("text").as_list::<i32>().value(0);
+    let text = text.as_struct();
+    assert_eq!(
+        text.column_by_name("kind").unwrap().as_string::<i32>(),
+        &StringArray::from(vec!["ATTACK", "RULE_BOX"])
+    );
+    assert_eq!(
+        text.column_by_name("damage")
+            .unwrap()
+            .as_primitive::<UInt16Type>(),
+        &UInt16Array::from(vec![Some(220), None])
+    );
+    let cost = text.column_by_name("cost").unwrap().as_list::<i32>();
+    assert_eq!(cost.value(0).len(), 3);
+    assert!(cost.is_null(1));
+}
@@ -0,0 +1,339 @@
+//! A catalog of every image asset referenced by a collection of cards, with a
+//! stable local path for each, and a plan for mirroring them to a local
+//! directory.
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet, btree_map},
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use url::Url;
+
+use crate::{Card, Images};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ImageFormat {
+    Jpg,
+    Png,
+    Tex,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Layer {
+    Front,
+    Foil,
+    Etch,
+}
+
+/// A single image of a card.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asset<'a> {
+    card_id: &'a str,
+    format: ImageFormat,
+    layer: Layer,
+    url: &'a Url,
+}
+
+impl<'a> Asset<'a> {
+    /// The `cardID` of the card the image belongs to. When the same image is
+    /// shared by several cards, this is the first of them.
+    #[must_use]
+    pub fn card_id(&self) -> &'a str {
+        self.card_id
+    }
+
+    #[must_use]
+    pub fn format(&self) -> ImageFormat {
+        self.format
+    }
+
+    #[must_use]
+    pub fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    #[must_use]
+    pub fn url(&self) -> &'a Url {
+        self.url
+    }
+
+    /// The path to store the image at, relative to the root of a mirror.
+    #[must_use]
+    pub fn path(&self) -> String {
+        local_path(self.url)
+    }
+}
+
+impl Images {
+    /// Every image, with the `cardID` it belongs to.
+    pub fn assets<'a>(&'a self, card_id: &'a str) -> impl Iterator<Item = Asset<'a>> {
+        let tcgl = &self.tcgl;
+
+        [
+            (ImageFormat::Jpg, Layer::Front, Some(&tcgl.jpg.front)),
+            (ImageFormat::Png, Layer::Front, Some(&tcgl.png.front)),
+            (ImageFormat::Png, Layer::Foil, tcgl.png.foil.as_ref()),
+            (ImageFormat::Png, Layer::Etch, tcgl.png.etch.as_ref()),
+            (ImageFormat::Tex, Layer::Front, Some(&tcgl.tex.front)),
+            (ImageFormat::Tex, Layer::Foil, tcgl.tex.foil.as_ref()),
+            (ImageFormat::Tex, Layer::Etch, tcgl.tex.etch.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(move |(format, layer, url)| {
+            Some(Asset {
+                card_id,
+                format,
+                layer,
+                url: url?,
+            })
+        })
+    }
+}
+
+/// Every distinct image of `cards`, in order.
+#[must_use]
+pub fn catalog<'a>(cards: &'a [Card<'_>]) -> Vec<Asset<'a>> {
+    let mut seen = BTreeSet::new();
+
+    cards
+        .iter()
+        .flat_map(|card| card.images().assets(card.tcgl().card_id.as_str()))
+        .filter(|asset| seen.insert(asset.url))
+        .collect()
+}
+
+/// The path to store the image at `url` at, relative to the root of a
+/// mirror: the host, followed by each segment of the URL's path. Any segment
+/// that could escape the mirror is replaced with `_`. A port is appended to
+/// the host after a `_`, and a query to the last segment after a `%3F`, the
+/// encoded `?`, with any `/` in it encoded as `%2F`.
+///
+/// ```
+/// let path = |url: &str| malie::assets::local_path(&url.parse().unwrap());
+///
+/// assert_eq!(
+///     path("https://cdn.malie.io/file/malie-io/tcgl/cards/png/en_US/SVI/SVI_EN_13.png"),
+///     "cdn.malie.io/file/malie-io/tcgl/cards/png/en_US/SVI/SVI_EN_13.png"
+/// );
+/// assert_eq!(
+///     path("https://example.com:8080/png/SVI_13.png?v=2/3"),
+///     "example.com_8080/png/SVI_13.png%3Fv=2%2F3"
+/// );
+/// ```
+#[must_use]
+pub fn local_path(url: &Url) -> String {
+    let mut path = String::from(url.host_str().unwrap_or("_"));
+    if let Some(port) = url.port() {
+        path.push('_');
+        path.push_str(&port.to_string());
+    }
+
+    for segment in url.path_segments().into_iter().flatten() {
+        path.push('/');
+        path.push_str(match segment {
+            "" | "." | ".." => "_",
+            segment => segment,
+        });
+    }
+
+    if let Some(query) = url.query() {
+        path.push_str("%3F");
+        path.push_str(&query.replace('/', "%2F"));
+    }
+
+    path
+}
+
+/// Two assets of a catalog that would be stored at the same local path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collision {
+    path: String,
+    urls: [String; 2],
+}
+
+impl Collision {
+    /// The local path both assets would be stored at.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The URLs of the two assets.
+    #[must_use]
+    pub fn urls(&self) -> [&str; 2] {
+        self.urls.each_ref().map(String::as_str)
+    }
+}
+
+impl fmt::Display for Collision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b] = &self.urls;
+        write!(f, "{a} and {b} would both be stored at {}", self.path)
+    }
+}
+
+impl core::error::Error for Collision {}
+
+/// What needs to change for a local directory to mirror a catalog.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MirrorPlan<'a> {
+    download: Vec<Asset<'a>>,
+    remove: Vec<String>,
+    unchanged: usize,
+}
+
+impl<'a> MirrorPlan<'a> {
+    /// Plan mirroring `catalog`, given the paths of every file that already
+    /// exists in the mirror, relative to its root and `/` separated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if two assets with different URLs would be stored at
+    /// the same [`local_path`].
+    pub fn new<'p>(
+        catalog: &[Asset<'a>],
+        existing: impl IntoIterator<Item = &'p str>,
+    ) -> Result<Self, Collision> {
+        let mut wanted = BTreeMap::new();
+        for asset in catalog {
+            match wanted.entry(asset.path()) {
+                btree_map::Entry::Vacant(entry) => {
+                    entry.insert(asset);
+                }
+                btree_map::Entry::Occupied(entry) if entry.get().url != asset.url => {
+                    return Err(Collision {
+                        path: entry.key().clone(),
+                        urls: [entry.get().url.to_string(), asset.url.to_string()],
+                    });
+                }
+                btree_map::Entry::Occupied(_) => {}
+            }
+        }
+
+        let mut plan = MirrorPlan::default();
+
+        for path in existing {
+            if wanted.remove(path).is_some() {
+                plan.unchanged += 1;
+            } else {
+                plan.remove.push(path.into());
+            }
+        }
+
+        plan.download = wanted.into_values().cloned().collect();
+        Ok(plan)
+    }
+
+    /// Assets missing from the mirror, ordered by path.
+    #[must_use]
+    pub fn download(&self) -> &[Asset<'a>] {
+        &self.download
+    }
+
+    /// Files in the mirror that aren't in the catalog.
+    #[must_use]
+    pub fn remove(&self) -> &[String] {
+        &self.remove
+    }
+
+    /// The number of assets already in the mirror.
+    #[must_use]
+    pub fn unchanged(&self) -> usize {
+        self.unchanged
+    }
+}
+
+#[test]
+fn plan() {
+    use crate::fixture::Fixture;
+
+    let cards = [Fixture::basic_energy()
+        .with(
+            "images",
+            serde_json::json!({"tcgl": {
+                "jpg": {"front": "https://example.com/jpg/SVE_1.jpg"},
+                "png": {"front": "https://example.com/png/SVE_1.png", "foil": "https://example.com/png/../SVE_1_foil.png"},
+                "tex": {"front": "https://example.com/tex/SVE_1.tex"}
+            }}),
+        )
+        .card()];
+
+    let catalog = catalog(&cards);
+    assert_eq!(catalog.len(), 4);
+
+    let plan = MirrorPlan::new(
+        &catalog,
+        ["example.com/jpg/SVE_1.jpg", "example.com/jpg/SVE_2.jpg"],
+    )
+    .unwrap();
+
+    assert_eq!(plan.unchanged(), 1);
+    assert_eq!(plan.remove(), ["example.com/jpg/SVE_2.jpg"]);
+    assert_eq!(
+        plan.download().iter().map(Asset::path).collect::<Vec<_>>(),
+        [
+            "example.com/SVE_1_foil.png",
+            "example.com/png/SVE_1.png",
+            "example.com/tex/SVE_1.tex",
+        ]
+    );
+}
+
+#[test]
+fn collisions() {
+    use alloc::format;
+
+    use serde_json::json;
+
+    use crate::fixture::Fixture;
+
+    let card = |card_id: &str, png: &str| {
+        Fixture::basic_energy()
+            .id(card_id)
+            .with(
+                "images",
+                json!({"tcgl": {
+                    "jpg": {"front": format!("https://example.com/jpg/{card_id}.jpg")},
+                    "png": {"front": png},
+                    "tex": {"front": format!("https://example.com/tex/{card_id}.tex")}
+                }}),
+            )
+            .card()
+    };
+
+    // only the query or port tells these apart
+    let cards = [
+        card("SVE_1", "https://example.com/png/SVE.png?v=1"),
+        card("SVE_2", "https://example.com/png/SVE.png?v=2"),
+        card("SVE_3", "https://example.com:8080/png/SVE.png"),
+        card("SVE_4", "https://example.com/png/SVE.png"),
+    ];
+    let plan = MirrorPlan::new(&catalog(&cards), []).unwrap();
+    assert_eq!(plan.download().len(), 12);
+    assert_eq!(
+        plan.download()
+            .iter()
+            .filter(|asset| asset.format() == ImageFormat::Png)
+            .map(Asset::path)
+            .collect::<Vec<_>>(),
+        [
+            "example.com/png/SVE.png",
+            "example.com/png/SVE.png%3Fv=1",
+            "example.com/png/SVE.png%3Fv=2",
+            "example.com_8080/png/SVE.png",
+        ]
+    );
+
+    let cards = [
+        card("SVE_1", "https://example.com/png/SVE.png%3Fv=1"),
+        card("SVE_2", "https://example.com/png/SVE.png?v=1"),
+    ];
+    let error = MirrorPlan::new(&catalog(&cards), []).unwrap_err();
+    assert_eq!(error.path(), "example.com/png/SVE.png%3Fv=1");
+    assert_eq!(
+        error.to_string(),
+        "https://example.com/png/SVE.png%3Fv=1 and https://example.com/png/SVE.png?v=1 would both be stored at example.com/png/SVE.png%3Fv=1"
+    );
+}
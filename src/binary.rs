@@ -0,0 +1,217 @@
+//! A compact, versioned binary encoding of the export.
+//!
+//! The encoding is self-describing (the model relies on internally tagged
+//! enums, which require it) and mirrors the shape of the JSON export exactly,
+//! so any value roundtrips losslessly between the two. All strings, including
+//! object keys, are stored once in a string table at the start of the
+//! encoding and referenced by index, which deduplicates the many repeated
+//! values in an export (`set_icon`, artist names, copyright text, ...). URLs
+//! are additionally split into an interned directory prefix and file name.
+//!
+//! Strings in the string table are borrowed from the input when deserializing,
+//! in the same way as they are from a JSON string with no escapes.
+//!
+//! # Layout
+//!
+//! ```text
+//! encoding := MAGIC VERSION string_table value
+//! string_table := varint(count) (varint(len) utf8_bytes){count}
+//! ```
+//!
+//! See [`Tag`] for the encoding of `value`. All integers are unsigned
+//! [LEB128] varints, except for negative integers which are zigzag encoded
+//! first.
+//!
+//! [LEB128]: https://en.wikipedia.org/wiki/LEB128
+
+use alloc::string::{String, ToString};
+use core::fmt;
+
+pub use self::{de::from_slice, ser::to_vec};
+
+mod de;
+mod ser;
+
+/// The leading bytes of every encoding.
+pub const MAGIC: [u8; 4] = *b"MLIE";
+
+/// The current version of the encoding. Decoding an encoding with any other
+/// version is an error.
+pub const VERSION: u8 = 1;
+
+/// The most sequences and maps a value can be nested in when decoding, so
+/// that malicious input can't overflow the stack.
+pub const MAX_DEPTH: usize = 128;
+
+/// The tag byte that precedes every value in the encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Tag {
+    /// `null`; `None` and unit values.
+    Null = 0,
+    False = 1,
+    True = 2,
+    /// Followed by a varint.
+    Uint = 3,
+    /// Followed by a zigzag encoded varint.
+    Int = 4,
+    /// Followed by the 8 little endian bytes of an `f64`.
+    Float = 5,
+    /// Followed by a varint index into the string table.
+    Str = 6,
+    /// Followed by two varint indices into the string table, a prefix and a
+    /// suffix, which are concatenated to form the string.
+    PrefixedStr = 7,
+    /// Followed by a varint length and that many bytes.
+    Bytes = 8,
+    /// Followed by a varint length and that many values.
+    Seq = 9,
+    /// Followed by a varint length and that many key-value pairs.
+    Map = 10,
+}
+
+impl Tag {
+    const ALL: [Tag; 11] = [
+        Tag::Null,
+        Tag::False,
+        Tag::True,
+        Tag::Uint,
+        Tag::Int,
+        Tag::Float,
+        Tag::Str,
+        Tag::PrefixedStr,
+        Tag::Bytes,
+        Tag::Seq,
+        Tag::Map,
+    ];
+
+    fn from_u8(tag: u8) -> Option<Self> {
+        Self::ALL.get(usize::from(tag)).copied()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    InvalidMagic,
+    UnsupportedVersion(u8),
+    UnexpectedEof,
+    InvalidTag(u8),
+    InvalidVarint,
+    InvalidUtf8,
+    InvalidStringIndex(u64),
+    TrailingBytes,
+    /// Values are nested more than [`MAX_DEPTH`] deep.
+    DepthLimitExceeded,
+    /// Sequences and maps must have a known length when serializing.
+    UnknownLength,
+    Custom(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidMagic => f.write_str("invalid magic bytes"),
+            Error::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported encoding version {version}, expected {VERSION}"
+            ),
+            Error::UnexpectedEof => f.write_str("unexpected end of input"),
+            Error::InvalidTag(tag) => write!(f, "invalid tag {tag}"),
+            Error::InvalidVarint => f.write_str("invalid varint"),
+            Error::InvalidUtf8 => f.write_str("invalid utf-8 in string table"),
+            Error::InvalidStringIndex(index) => write!(f, "invalid string index {index}"),
+            Error::TrailingBytes => f.write_str("trailing bytes after value"),
+            Error::DepthLimitExceeded => {
+                write!(f, "values nested more than {MAX_DEPTH} deep")
+            }
+            Error::UnknownLength => f.write_str("sequences and maps must have a known length"),
+            Error::Custom(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+#[test]
+fn roundtrip() {
+    use alloc::{borrow::Cow, vec::Vec};
+
+    use crate::{
+        Card, Trainer,
+        fixture::{Fixture, export},
+    };
+
+    let json = export([
+        &Fixture::item().with(
+            "foil",
+            serde_json::json!({"type": "FLAT_SILVER", "mask": "REVERSE"}),
+        ),
+        &Fixture::basic_energy(),
+    ]);
+
+    let cards = serde_json::from_str::<Vec<Card>>(&json).unwrap();
+
+    let bytes = to_vec(&cards).unwrap();
+    let decoded = from_slice::<Vec<Card>>(&bytes).unwrap();
+
+    assert_eq!(decoded, cards);
+
+    let Card::Trainer(Trainer::Item(item)) = &decoded[0] else {
+        panic!("expected an item");
+    };
+    assert!(matches!(item.name, Cow::Borrowed("Nest Ball")));
+
+    assert_eq!(
+        serde_json::to_value(&decoded).unwrap(),
+        serde_json::from_str::<serde_json::Value>(&json).unwrap()
+    );
+
+    assert!(bytes.len() < serde_json::to_vec(&cards).unwrap().len());
+
+    let mut unsupported = bytes.clone();
+    unsupported[MAGIC.len()] = VERSION + 1;
+    assert_eq!(
+        from_slice::<Vec<Card>>(&unsupported),
+        Err(Error::UnsupportedVersion(VERSION + 1))
+    );
+}
+
+#[test]
+fn depth_limit() {
+    use alloc::vec::Vec;
+
+    use serde_json::Value;
+
+    // an empty string table, then `depth` nested single element sequences
+    let nested = |depth: usize| {
+        let mut bytes = Vec::from(MAGIC);
+        bytes.extend([VERSION, 0]);
+        for _ in 0..depth {
+            bytes.extend([Tag::Seq as u8, 1]);
+        }
+        bytes.push(Tag::Null as u8);
+        bytes
+    };
+
+    assert!(from_slice::<Value>(&nested(MAX_DEPTH)).is_ok());
+    assert_eq!(
+        from_slice::<Value>(&nested(MAX_DEPTH + 1)),
+        Err(Error::DepthLimitExceeded)
+    );
+    assert_eq!(
+        from_slice::<Value>(&nested(100_000)),
+        Err(Error::DepthLimitExceeded)
+    );
+}
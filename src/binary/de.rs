@@ -0,0 +1,299 @@
+use alloc::{string::String, vec::Vec};
+
+use serde::{
+    Deserialize,
+    de::{self, IntoDeserializer, Visitor, value::BorrowedStrDeserializer},
+};
+
+use super::{Error, MAGIC, MAX_DEPTH, Tag, VERSION};
+
+/// Deserialize a `T` from the binary encoding, borrowing strings from `bytes`
+/// where possible.
+///
+/// # Errors
+///
+/// Returns an error if `bytes` is not a valid encoding of the current
+/// [`VERSION`], or if `T` fails to deserialize from it.
+pub fn from_slice<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T, Error> {
+    let input = bytes.strip_prefix(&MAGIC).ok_or(Error::InvalidMagic)?;
+    let (&version, input) = input.split_first().ok_or(Error::UnexpectedEof)?;
+    if version != VERSION {
+        return Err(Error::UnsupportedVersion(version));
+    }
+
+    let mut deserializer = Deserializer {
+        input,
+        strings: Vec::new(),
+        depth: 0,
+    };
+
+    let count = deserializer.varint()?;
+    for _ in 0..count {
+        let len = deserializer.len()?;
+        let bytes = deserializer.take(len)?;
+        deserializer
+            .strings
+            .push(core::str::from_utf8(bytes).map_err(|_| Error::InvalidUtf8)?);
+    }
+
+    let value = T::deserialize(&mut deserializer)?;
+
+    if deserializer.input.is_empty() {
+        Ok(value)
+    } else {
+        Err(Error::TrailingBytes)
+    }
+}
+
+struct Deserializer<'de> {
+    input: &'de [u8],
+    strings: Vec<&'de str>,
+    /// The number of sequences and maps the current value is nested in.
+    depth: usize,
+}
+
+impl<'de> Deserializer<'de> {
+    fn take(&mut self, len: usize) -> Result<&'de [u8], Error> {
+        if self.input.len() < len {
+            return Err(Error::UnexpectedEof);
+        }
+        let (bytes, rest) = self.input.split_at(len);
+        self.input = rest;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, Error> {
+        let (&byte, rest) = self.input.split_first().ok_or(Error::UnexpectedEof)?;
+        self.input = rest;
+        Ok(byte)
+    }
+
+    fn peek_tag(&self) -> Result<Tag, Error> {
+        let &tag = self.input.first().ok_or(Error::UnexpectedEof)?;
+        Tag::from_u8(tag).ok_or(Error::InvalidTag(tag))
+    }
+
+    fn tag(&mut self) -> Result<Tag, Error> {
+        let tag = self.byte()?;
+        Tag::from_u8(tag).ok_or(Error::InvalidTag(tag))
+    }
+
+    fn varint(&mut self) -> Result<u64, Error> {
+        let mut value = 0_u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            if shift == 63 && byte > 1 {
+                return Err(Error::InvalidVarint);
+            }
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(Error::InvalidVarint)
+    }
+
+    fn len(&mut self) -> Result<usize, Error> {
+        usize::try_from(self.varint()?).map_err(|_| Error::UnexpectedEof)
+    }
+
+    fn string(&mut self) -> Result<&'de str, Error> {
+        let index = self.varint()?;
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| self.strings.get(i).copied())
+            .ok_or(Error::InvalidStringIndex(index))
+    }
+
+    /// Deserialize the contents of a sequence or map with `f`, one level
+    /// deeper.
+    fn nested<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T, Error>) -> Result<T, Error> {
+        if self.depth == MAX_DEPTH {
+            return Err(Error::DepthLimitExceeded);
+        }
+        self.depth += 1;
+        let value = f(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn prefixed_string(&mut self) -> Result<String, Error> {
+        let prefix = self.string()?;
+        let suffix = self.string()?;
+        let mut s = String::with_capacity(prefix.len() + suffix.len());
+        s.push_str(prefix);
+        s.push_str(suffix);
+        Ok(s)
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.tag()? {
+            Tag::Null => visitor.visit_unit(),
+            Tag::False => visitor.visit_bool(false),
+            Tag::True => visitor.visit_bool(true),
+            Tag::Uint => visitor.visit_u64(self.varint()?),
+            Tag::Int => {
+                let zigzag = self.varint()?;
+                #[expect(clippy::cast_possible_wrap, reason = "zigzag encoding")]
+                let value = (zigzag >> 1) as i64 ^ -((zigzag & 1) as i64);
+                visitor.visit_i64(value)
+            }
+            Tag::Float => visitor.visit_f64(f64::from_le_bytes(
+                self.take(8)?.try_into().expect("took 8 bytes"),
+            )),
+            Tag::Str => visitor.visit_borrowed_str(self.string()?),
+            Tag::PrefixedStr => visitor.visit_string(self.prefixed_string()?),
+            Tag::Bytes => {
+                let len = self.len()?;
+                visitor.visit_borrowed_bytes(self.take(len)?)
+            }
+            Tag::Seq => {
+                let remaining = self.len()?;
+                self.nested(|de| visitor.visit_seq(Access { de, remaining }))
+            }
+            Tag::Map => {
+                let remaining = self.len()?;
+                self.nested(|de| visitor.visit_map(Access { de, remaining }))
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.peek_tag()? == Tag::Null {
+            self.tag()?;
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.tag()? {
+            Tag::Str => visitor.visit_enum(BorrowedStrDeserializer::new(self.string()?)),
+            Tag::PrefixedStr => visitor.visit_enum(self.prefixed_string()?.into_deserializer()),
+            Tag::Map if self.len()? == 1 => self.nested(|de| visitor.visit_enum(de)),
+            tag => Err(de::Error::invalid_type(
+                unexpected(tag),
+                &"a string or a map with a single key",
+            )),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+fn unexpected(tag: Tag) -> de::Unexpected<'static> {
+    match tag {
+        Tag::Null => de::Unexpected::Unit,
+        Tag::False => de::Unexpected::Bool(false),
+        Tag::True => de::Unexpected::Bool(true),
+        Tag::Uint | Tag::Int => de::Unexpected::Other("integer"),
+        Tag::Float => de::Unexpected::Other("float"),
+        Tag::Str | Tag::PrefixedStr => de::Unexpected::Other("string"),
+        Tag::Bytes => de::Unexpected::Other("bytes"),
+        Tag::Seq => de::Unexpected::Seq,
+        Tag::Map => de::Unexpected::Map,
+    }
+}
+
+struct Access<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+    remaining: usize,
+}
+
+impl<'de> de::SeqAccess<'de> for Access<'_, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de> de::MapAccess<'de> for Access<'_, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de> de::EnumAccess<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self), Error> {
+        let variant = seed.deserialize(&mut *self)?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        <()>::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_any(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_any(self, visitor)
+    }
+}
@@ -0,0 +1,235 @@
+//! Release calendar queries over a collection of cards, for historical
+//! analysis: what was released in a range of time, what was legal in the
+//! Standard format on a date, and the newest printing of a card on a date.
+
+use alloc::vec::Vec;
+use core::ops::{Bound, RangeBounds};
+
+use time::{Date, UtcDateTime, macros::date};
+
+use crate::{
+    Card, Energy, RegulationMark, Tcgl,
+    id::ArchetypeId,
+    set::{Set, Sets},
+};
+
+/// The dates of each rotation of the Standard format, with the oldest
+/// regulation mark that remained legal. Rotations before regulation marks
+/// alone decided legality aren't included.
+const STANDARD_ROTATIONS: [(Date, RegulationMark); 5] = [
+    (date!(2026 - 04 - 10), RegulationMark::H),
+    (date!(2025 - 04 - 11), RegulationMark::G),
+    (date!(2024 - 04 - 05), RegulationMark::F),
+    (date!(2023 - 04 - 14), RegulationMark::E),
+    (date!(2022 - 07 - 15), RegulationMark::D),
+];
+
+/// The oldest regulation mark legal in the Standard format on `on`, or
+/// `None` if `on` is before the earliest known rotation.
+#[must_use]
+pub fn oldest_standard_mark(on: Date) -> Option<&'static RegulationMark> {
+    STANDARD_ROTATIONS
+        .iter()
+        .find_map(|(start, mark)| (on >= *start).then_some(mark))
+}
+
+impl Tcgl<'_> {
+    #[must_use]
+    pub fn reldate(&self) -> UtcDateTime {
+        self.reldate
+    }
+}
+
+impl Card<'_> {
+    /// The release date of the card.
+    #[must_use]
+    pub fn reldate(&self) -> UtcDateTime {
+        self.tcgl().reldate
+    }
+
+    /// Whether the card was legal in the Standard format on `on`, or `None`
+    /// if `on` is before the earliest known rotation. Cards are counted as
+    /// legal from their release date, rather than the later date a set
+    /// becomes legal for tournaments.
+    #[must_use]
+    pub fn is_standard_legal(&self, on: Date) -> Option<bool> {
+        let oldest = oldest_standard_mark(on)?;
+
+        Some(
+            self.reldate().date() <= on
+                && match self.regulation_mark() {
+                    Some(mark) => mark >= oldest,
+                    // basic energy is always legal
+                    None => matches!(self, Card::Energy(Energy::Basic(_))),
+                },
+        )
+    }
+}
+
+/// The cards of a collection, in the order they were released.
+#[derive(Debug, Clone)]
+pub struct Calendar<'c, 'a> {
+    cards: Vec<&'c Card<'a>>,
+}
+
+impl<'c, 'a> Calendar<'c, 'a> {
+    #[must_use]
+    pub fn new(cards: &'c [Card<'a>]) -> Self {
+        let mut cards = cards.iter().collect::<Vec<_>>();
+        cards.sort_by_key(|card| card.reldate());
+
+        Self { cards }
+    }
+
+    /// Every card released within `range`, in the order they were released.
+    #[must_use]
+    pub fn released(&self, range: impl RangeBounds<UtcDateTime>) -> &[&'c Card<'a>] {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.cards.partition_point(|card| card.reldate() < *start),
+            Bound::Excluded(start) => self.cards.partition_point(|card| card.reldate() <= *start),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.cards.partition_point(|card| card.reldate() <= *end),
+            Bound::Excluded(end) => self.cards.partition_point(|card| card.reldate() < *end),
+            Bound::Unbounded => self.cards.len(),
+        };
+
+        self.cards.get(start..end).unwrap_or_default()
+    }
+
+    /// Every card legal in the Standard format on `on`, or `None` if `on` is
+    /// before the earliest known rotation.
+    #[must_use]
+    pub fn standard_legal(&self, on: Date) -> Option<Vec<&'c Card<'a>>> {
+        oldest_standard_mark(on)?;
+
+        Some(
+            self.cards
+                .iter()
+                .copied()
+                .filter(|card| card.is_standard_legal(on) == Some(true))
+                .collect(),
+        )
+    }
+
+    /// The most recently released printing of the archetype as of `as_of`.
+    #[must_use]
+    pub fn newest_printing(
+        &self,
+        archetype_id: ArchetypeId,
+        as_of: UtcDateTime,
+    ) -> Option<&'c Card<'a>> {
+        self.released(..=as_of)
+            .iter()
+            .rev()
+            .find(|card| card.tcgl().archetype_id == archetype_id)
+            .copied()
+    }
+}
+
+impl<'c> Sets<'c> {
+    /// Every set released within `range`, in the order they were released.
+    pub fn released(&self, range: impl RangeBounds<UtcDateTime>) -> impl Iterator<Item = &Set<'c>> {
+        self.iter()
+            .filter(move |set| range.contains(&set.reldate()))
+    }
+}
+
+#[test]
+fn standard() {
+    use serde_json::json;
+    use time::macros::utc_datetime;
+
+    use crate::fixture::Fixture;
+
+    let card = |number: u16, mark: &str, reldate: &str| {
+        Fixture::item()
+            .id(&alloc::format!("SVI_{number}"))
+            .number(&alloc::format!("{number}"))
+            .with("regulation_mark", json!(mark))
+            .tcgl("archetypeID", json!("0x00000001"))
+            .tcgl("reldate", json!(reldate))
+            .card()
+    };
+
+    let cards = [
+        card(3, "H", "2024-03-22 00:00:00+00:00"),
+        card(1, "E", "2021-02-19 00:00:00+00:00"),
+        card(2, "G", "2023-03-31 09:00:00+00:00"),
+    ];
+    assert_eq!(cards[2].reldate(), utc_datetime!(2023-03-31 09:00:00));
+
+    let calendar = Calendar::new(&cards);
+    assert_eq!(
+        calendar
+            .released(utc_datetime!(2023-01-01 00:00)..)
+            .iter()
+            .map(|card| card.collector_number().parts().number())
+            .collect::<Vec<_>>(),
+        [Some(2), Some(3)]
+    );
+
+    let legal = calendar.standard_legal(date!(2024 - 01 - 01)).unwrap();
+    assert_eq!(legal.len(), 2);
+    assert_eq!(
+        cards[1].is_standard_legal(date!(2024 - 06 - 01)),
+        Some(false)
+    );
+    assert!(calendar.standard_legal(date!(2022 - 01 - 01)).is_none());
+
+    assert_eq!(
+        calendar
+            .newest_printing(ArchetypeId::new(1), utc_datetime!(2024-01-01 00:00))
+            .map(|card| card.tcgl().card_id.as_str()),
+        Some("SVI_2")
+    );
+}
+
+#[test]
+fn rotations() {
+    use serde_json::json;
+
+    use crate::fixture::Fixture;
+
+    assert_eq!(oldest_standard_mark(date!(2022 - 07 - 14)), None);
+    assert_eq!(
+        oldest_standard_mark(date!(2022 - 07 - 15)),
+        Some(&RegulationMark::D)
+    );
+    assert_eq!(
+        oldest_standard_mark(date!(2023 - 04 - 13)),
+        Some(&RegulationMark::D)
+    );
+    assert_eq!(
+        oldest_standard_mark(date!(2023 - 04 - 14)),
+        Some(&RegulationMark::E)
+    );
+    assert_eq!(
+        oldest_standard_mark(date!(2030 - 01 - 01)),
+        Some(&RegulationMark::H)
+    );
+
+    let card = |mark: &str| {
+        Fixture::item()
+            .with("regulation_mark", json!(mark))
+            .reldate("2021-11-12")
+            .card()
+    };
+    let d = card("D");
+    assert_eq!(d.is_standard_legal(date!(2022 - 09 - 01)), Some(true));
+    assert_eq!(d.is_standard_legal(date!(2023 - 04 - 14)), Some(false));
+    assert_eq!(
+        card("C").is_standard_legal(date!(2022 - 09 - 01)),
+        Some(false)
+    );
+    // not yet released
+    let later = Fixture::item()
+        .with("regulation_mark", json!("G"))
+        .reldate("2023-03-31")
+        .card();
+    assert_eq!(later.is_standard_legal(date!(2022 - 09 - 01)), Some(false));
+    assert_eq!(later.is_standard_legal(date!(2023 - 03 - 31)), Some(true));
+    let energy = Fixture::basic_energy().reldate("2021-11-12").card();
+    assert_eq!(energy.is_standard_legal(date!(2022 - 09 - 01)), Some(true));
+}
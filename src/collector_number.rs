@@ -0,0 +1,216 @@
+//! Structured parsing of [`CollectorNumber`]s, i.e. `"244/198"`, `"TG05/TG30"`
+//! or `"SVP 001"`, and as printed on Asian-market cards, i.e. `"SV1S 061/078"`
+//! or `"001/SV-P"`.
+
+use core::cmp::Ordering;
+
+use crate::{Card, CollectorNumber};
+
+/// The section of a set a card is printed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Section {
+    /// Numbered up to, and including, the size of the set.
+    Main,
+    /// Numbered past the size of the set, i.e. `244/198`.
+    Secret,
+    /// Numbered with a prefix, i.e. a trainer gallery (`TG05/TG30`), galarian
+    /// gallery (`GG12/GG70`) or promo (`SVP 001`, `001/SV-P`).
+    Subset,
+}
+
+/// A collector number split into its parts.
+///
+/// Ordering sorts a set the way it is printed: the main set, then secret
+/// rares, then each subset.
+///
+/// ```
+/// use malie::collector_number::{Parts, Section};
+///
+/// let parts = Parts::parse("TG05", Some("TG30"));
+/// assert_eq!(parts.prefix(), "TG");
+/// assert_eq!(parts.number(), Some(5));
+/// assert_eq!(parts.section(), Section::Subset);
+///
+/// let mut numbers = [
+///     Parts::parse("TG05", Some("TG30")),
+///     Parts::parse("244", Some("198")),
+///     Parts::parse("081", Some("198")),
+/// ];
+/// numbers.sort();
+/// assert_eq!(numbers.map(|parts| parts.number()), [Some(81), Some(244), Some(5)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Parts<'a> {
+    set_code: Option<&'a str>,
+    prefix: &'a str,
+    number: Option<u16>,
+    suffix: &'a str,
+    denominator: Option<&'a str>,
+}
+
+impl<'a> Parts<'a> {
+    /// Split a collector number into its parts. Parsing never fails: anything
+    /// that isn't a number is kept in the prefix or suffix.
+    #[must_use]
+    pub fn parse(numerator: &'a str, denominator: Option<&'a str>) -> Self {
+        // only a number out of the size of a set can follow a set code, a
+        // promo code like `SVP 001` is a prefix
+        let sized = denominator.is_some_and(|denominator| {
+            !denominator.is_empty() && denominator.bytes().all(|b| b.is_ascii_digit())
+        });
+        let (set_code, numerator) = match numerator.rsplit_once(' ') {
+            Some((set_code, numerator)) if sized => (Some(set_code.trim_end()), numerator),
+            _ => (None, numerator),
+        };
+        let (prefix, number, suffix) = split(numerator);
+
+        Self {
+            set_code,
+            prefix,
+            number,
+            suffix,
+            denominator,
+        }
+    }
+
+    /// The code of the set printed before the number on Asian-market cards,
+    /// i.e. `SV1S` of `SV1S 061/078`.
+    #[must_use]
+    pub fn set_code(&self) -> Option<&'a str> {
+        self.set_code
+    }
+
+    /// Letters before the number, i.e. `TG` or `SVP`, without any separator.
+    #[must_use]
+    pub fn prefix(&self) -> &'a str {
+        self.prefix
+    }
+
+    #[must_use]
+    pub fn number(&self) -> Option<u16> {
+        self.number
+    }
+
+    /// Anything after the number, i.e. the `a` of `177a`.
+    #[must_use]
+    pub fn suffix(&self) -> &'a str {
+        self.suffix
+    }
+
+    /// The size of the set or subset, i.e. `198` or `TG30`, or a set code
+    /// like `SV-P` for promos.
+    #[must_use]
+    pub fn denominator(&self) -> Option<&'a str> {
+        self.denominator
+    }
+
+    #[must_use]
+    pub fn section(&self) -> Section {
+        if !self.prefix.is_empty() {
+            return Section::Subset;
+        }
+
+        match (self.number, self.denominator.map(split)) {
+            (Some(number), Some(("", Some(size), ""))) if number > size => Section::Secret,
+            (_, Some(("", Some(_), "")) | None) => Section::Main,
+            // numbered against a set code rather than a size, i.e. `001/SV-P`
+            (_, Some(_)) => Section::Subset,
+        }
+    }
+}
+
+impl Ord for Parts<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.section()
+            .cmp(&other.section())
+            .then_with(|| self.prefix.cmp(other.prefix))
+            .then_with(|| self.number.cmp(&other.number))
+            .then_with(|| self.suffix.cmp(other.suffix))
+            .then_with(|| self.denominator.cmp(&other.denominator))
+            .then_with(|| self.set_code.cmp(&other.set_code))
+    }
+}
+
+impl PartialOrd for Parts<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl CollectorNumber<'_> {
+    /// The collector number split into its parts, for sorting cards in print
+    /// order.
+    #[must_use]
+    pub fn parts(&self) -> Parts<'_> {
+        Parts::parse(&self.numerator, self.denominator.as_deref())
+    }
+}
+
+impl Card<'_> {
+    /// The section of its set the card is printed in.
+    #[must_use]
+    pub fn section(&self) -> Section {
+        self.collector_number().parts().section()
+    }
+
+    /// Whether the card is a secret rare, numbered past the size of its set.
+    #[must_use]
+    pub fn is_secret(&self) -> bool {
+        self.section() == Section::Secret
+    }
+}
+
+/// Split `s` into a prefix, number and suffix, dropping any separator between
+/// the prefix and the number.
+fn split(s: &str) -> (&str, Option<u16>, &str) {
+    let start = s.find(|c: char| c.is_ascii_digit()).unwrap_or(s.len());
+    let end = s[start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(s.len(), |end| start + end);
+
+    let prefix = s[..start].trim_end_matches([' ', '-']);
+
+    match s[start..end].parse() {
+        Ok(number) => (prefix, Some(number), &s[end..]),
+        // a number too large for a `u16` can't be a real collector number
+        Err(_) => (s, None, ""),
+    }
+}
+
+#[test]
+fn subsets_and_promos() {
+    assert_eq!(
+        Parts::parse("SVP 001", None),
+        Parts {
+            set_code: None,
+            prefix: "SVP",
+            number: Some(1),
+            suffix: "",
+            denominator: None,
+        }
+    );
+    assert_eq!(Parts::parse("001", Some("SV-P")).section(), Section::Subset);
+    assert_eq!(Parts::parse("001", None).section(), Section::Main);
+    assert_eq!(
+        Parts::parse("GG12", Some("GG70")).section(),
+        Section::Subset
+    );
+    assert_eq!(Parts::parse("177a", Some("172")).suffix(), "a");
+    assert_eq!(Parts::parse("SV-P", None).prefix(), "SV-P");
+}
+
+#[test]
+fn asian_markets() {
+    let parts = Parts::parse("SV1S 061", Some("078"));
+    assert_eq!(parts.set_code(), Some("SV1S"));
+    assert_eq!(parts.prefix(), "");
+    assert_eq!(parts.number(), Some(61));
+    assert_eq!(parts.section(), Section::Main);
+
+    assert_eq!(
+        Parts::parse("S12a 250", Some("172")).section(),
+        Section::Secret
+    );
+    assert_eq!(Parts::parse("001", Some("SV-P")).set_code(), None);
+    assert_eq!(Parts::parse("001", Some("S-P")).section(), Section::Subset);
+}
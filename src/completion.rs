@@ -0,0 +1,180 @@
+//! Set completion tracking for a collection of owned cards.
+
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use crate::{Card, RarityDesignation, collector_number::Section, id::CardId};
+
+/// How many of the distinct cards in a group are owned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Progress {
+    owned: usize,
+    total: usize,
+}
+
+impl Progress {
+    #[must_use]
+    pub fn owned(&self) -> usize {
+        self.owned
+    }
+
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    #[must_use]
+    pub fn missing(&self) -> usize {
+        self.total - self.owned
+    }
+
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.owned == self.total
+    }
+
+    fn add(&mut self, owned: bool) {
+        self.total += 1;
+        if owned {
+            self.owned += 1;
+        }
+    }
+}
+
+/// Completion of a single set.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetCompletion {
+    master: Progress,
+    main: Progress,
+    rarities: BTreeMap<RarityDesignation, Progress>,
+}
+
+impl SetCompletion {
+    /// Every card in the set, including secret rares and subsets.
+    #[must_use]
+    pub fn master(&self) -> Progress {
+        self.master
+    }
+
+    /// Only the cards in the [`Section::Main`] of the set.
+    #[must_use]
+    pub fn main(&self) -> Progress {
+        self.main
+    }
+
+    /// Every card in the set with the given rarity.
+    #[must_use]
+    pub fn rarity(&self, designation: &RarityDesignation) -> Progress {
+        self.rarities.get(designation).copied().unwrap_or_default()
+    }
+
+    /// Progress for every rarity that appears in the set.
+    pub fn rarities(&self) -> impl Iterator<Item = (&RarityDesignation, Progress)> {
+        self.rarities
+            .iter()
+            .map(|(designation, progress)| (designation, *progress))
+    }
+}
+
+/// Report the completion of every set in `cards`, keyed by `set_icon`, given
+/// the number of copies `owned` of each card, keyed by `cardID`.
+///
+/// Cards are counted once per `cardID`, so an export containing the same
+/// cards in several languages is counted the same as one containing a single
+/// language.
+#[must_use]
+pub fn completion<'c>(
+    cards: &'c [Card<'_>],
+    owned: &BTreeMap<CardId<'_>, u32>,
+) -> BTreeMap<&'c str, SetCompletion> {
+    let mut sets = BTreeMap::<&str, SetCompletion>::new();
+    let mut seen = BTreeSet::new();
+
+    for card in cards {
+        let card_id = card.tcgl().card_id();
+        if !seen.insert((card.set_icon(), card_id)) {
+            continue;
+        }
+
+        let is_owned = owned.get(card_id.as_str()).is_some_and(|&count| count > 0);
+        let set = sets.entry(card.set_icon()).or_default();
+
+        set.master.add(is_owned);
+        if card.section() == Section::Main {
+            set.main.add(is_owned);
+        }
+        if let Some(rarity) = card.rarity() {
+            set.rarities
+                .entry(rarity.designation.clone())
+                .or_default()
+                .add(is_owned);
+        }
+    }
+
+    sets
+}
+
+#[test]
+fn master_and_main() {
+    use crate::fixture::Fixture;
+
+    let card = |number: &str, card_id: &str, rarity: &str| {
+        Fixture::item()
+            .id(card_id)
+            .number(&alloc::format!("{number}/198"))
+            .with(
+                "rarity",
+                serde_json::json!({"designation": rarity, "icon": "SOLID_DIAMOND"}),
+            )
+            .card()
+    };
+
+    let cards = [
+        card("181", "SVI_181", "UNCOMMON"),
+        card("255", "SVI_255", "HYPER_RARE"),
+    ];
+    let owned = BTreeMap::from([(CardId::new("SVI_181"), 2), (CardId::new("SVI_255"), 0)]);
+
+    let sets = completion(&cards, &owned);
+    let svi = &sets["SVI"];
+
+    assert_eq!(svi.master(), Progress { owned: 1, total: 2 });
+    assert!(svi.main().is_complete());
+    assert_eq!(svi.rarity(&RarityDesignation::HyperRare).missing(), 1);
+}
+
+#[test]
+fn edge_cases() {
+    use serde_json::json;
+
+    use crate::fixture::Fixture;
+
+    let cards = [
+        Fixture::item().card(),
+        // the same card in another language is counted once
+        Fixture::item()
+            .with("lang", json!("fr-FR"))
+            .with("name", json!("Poké Ball Nid"))
+            .card(),
+        Fixture::pokemon().card(),
+        Fixture::item().id("PAL_181").number("181/193").card(),
+    ];
+
+    let sets = completion(&cards, &BTreeMap::new());
+    assert_eq!(
+        sets.keys().copied().collect::<alloc::vec::Vec<_>>(),
+        ["PAL", "SVI"]
+    );
+    assert_eq!(sets["SVI"].master(), Progress { owned: 0, total: 2 });
+    assert_eq!(sets["SVI"].main().missing(), 2);
+    assert!(!sets["PAL"].master().is_complete());
+    // no card has a rarity
+    assert_eq!(sets["SVI"].rarities().count(), 0);
+    assert_eq!(
+        sets["SVI"].rarity(&RarityDesignation::Common),
+        Progress::default()
+    );
+
+    let owned = BTreeMap::from([(CardId::new("SVI_181"), 1), (CardId::new("SVI_81"), 1)]);
+    assert!(completion(&cards, &owned)["SVI"].master().is_complete());
+    assert!(completion(&[], &owned).is_empty());
+}
@@ -0,0 +1,202 @@
+//! How to composite the image layers of a foil card into a single render.
+//!
+//! A foil card is rendered from up to three layers of the same size:
+//!
+//! - the `front`, the card as printed without any foil
+//! - the `foil` mask, marking where the foil pattern of the [`FoilType`] shows
+//!   through
+//! - the `etch` texture, for masks that are embossed into the card
+//!
+//! The pattern itself is not an image: it is drawn by the renderer for the
+//! [`FoilType`], and blended over the front through the mask.
+//!
+//! The export has no notion of blending: the [`BlendMode`] of each
+//! [`FoilType`] and [`FoilMask`] is a rendering convention that approximates
+//! the printed card, not data from the source.
+
+use crate::{Card, Foil, FoilMask, FoilType, assets::Layer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    Normal,
+    Screen,
+    Overlay,
+    ColorDodge,
+    SoftLight,
+}
+
+/// How to render a [`Foil`]. Built from the foil alone, every layer it
+/// needs is assumed to have an image; use [`Card::composite`] to leave out the
+/// layers a card has no image for.
+///
+/// ```
+/// use malie::{assets::Layer, composite::{BlendMode, Composite}};
+///
+/// # let foil = serde_json::from_str::<malie::Foil>(r#"{"type": "FLAT_SILVER", "mask": "REVERSE"}"#).unwrap();
+/// let composite = Composite::new(&foil);
+///
+/// assert_eq!(composite.pattern_blend(), BlendMode::Screen);
+/// assert!(composite.inverted());
+/// assert_eq!(composite.layers().collect::<Vec<_>>(), [Layer::Front, Layer::Foil]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Composite {
+    pattern: FoilType,
+    pattern_blend: BlendMode,
+    inverted: bool,
+    etch_blend: Option<BlendMode>,
+    has_foil: bool,
+}
+
+impl Composite {
+    #[must_use]
+    pub fn new(foil: &Foil) -> Self {
+        let pattern_blend = match foil.ty {
+            FoilType::Stamped | FoilType::FlatSilver | FoilType::SvUltra | FoilType::AceFoil => {
+                BlendMode::Screen
+            }
+            FoilType::Cosmos
+            | FoilType::SunPillar
+            | FoilType::SvHolo
+            | FoilType::CrackedIce
+            | FoilType::Tinsel => BlendMode::ColorDodge,
+            FoilType::Rainbow | FoilType::UltraGoldRainbow | FoilType::SvUltraScodix => {
+                BlendMode::Overlay
+            }
+        };
+
+        let etch_blend = match foil.mask {
+            FoilMask::Etched | FoilMask::CastAndCure => Some(BlendMode::Overlay),
+            FoilMask::ColdFoilEtched => Some(BlendMode::SoftLight),
+            FoilMask::Stamped | FoilMask::Reverse | FoilMask::Holo | FoilMask::ReverseLaminate => {
+                None
+            }
+        };
+
+        Self {
+            pattern: foil.ty.clone(),
+            pattern_blend,
+            inverted: matches!(foil.mask, FoilMask::Reverse | FoilMask::ReverseLaminate),
+            etch_blend,
+            has_foil: true,
+        }
+    }
+
+    /// The foil pattern to draw.
+    #[must_use]
+    pub fn pattern(&self) -> &FoilType {
+        &self.pattern
+    }
+
+    /// How to blend the pattern over the front, through the foil mask.
+    #[must_use]
+    pub fn pattern_blend(&self) -> BlendMode {
+        self.pattern_blend
+    }
+
+    /// Whether the foil mask is inverted, so that the pattern shows
+    /// everywhere *except* the marked area, i.e. for reverse holos.
+    #[must_use]
+    pub fn inverted(&self) -> bool {
+        self.inverted
+    }
+
+    /// How to blend the etch texture over the result, if the mask is etched
+    /// and there is an etch texture.
+    #[must_use]
+    pub fn etch_blend(&self) -> Option<BlendMode> {
+        self.etch_blend
+    }
+
+    /// The image layers needed, in the order they are composited.
+    pub fn layers(&self) -> impl Iterator<Item = Layer> {
+        [
+            Some(Layer::Front),
+            self.has_foil.then_some(Layer::Foil),
+            self.etch_blend.map(|_| Layer::Etch),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+impl Card<'_> {
+    /// How to render the card, or `None` if it isn't a foil and only the
+    /// front layer is needed. Layers without an image in the export are left
+    /// out, i.e. an etched mask without an etch texture has no
+    /// [`etch_blend`](Composite::etch_blend).
+    #[must_use]
+    pub fn composite(&self) -> Option<Composite> {
+        let mut composite = Composite::new(self.foil()?);
+
+        let images = &self.images().tcgl;
+        composite.has_foil = images.png.foil.is_some() || images.tex.foil.is_some();
+        if images.png.etch.is_none() && images.tex.etch.is_none() {
+            composite.etch_blend = None;
+        }
+
+        Some(composite)
+    }
+}
+
+#[test]
+fn etched() {
+    let composite = Composite::new(&Foil {
+        ty: FoilType::SvUltra,
+        mask: FoilMask::Etched,
+    });
+
+    assert!(!composite.inverted());
+    assert_eq!(composite.etch_blend(), Some(BlendMode::Overlay));
+    assert_eq!(
+        composite.layers().collect::<alloc::vec::Vec<_>>(),
+        [Layer::Front, Layer::Foil, Layer::Etch]
+    );
+}
+
+#[test]
+fn images() {
+    use alloc::vec::Vec;
+
+    use serde_json::json;
+
+    use crate::fixture::Fixture;
+
+    // the foil and etch images of the card in each format
+    let card = |png: serde_json::Value, tex: serde_json::Value| {
+        let base = Fixture::pokemon();
+        let mut images = base.value()["images"].clone();
+        for (format, layers) in [("png", png), ("tex", tex)] {
+            for (layer, url) in layers.as_object().unwrap() {
+                images["tcgl"][format][layer] = url.clone();
+            }
+        }
+        base.with("foil", json!({"type": "SV_ULTRA", "mask": "ETCHED"}))
+            .with("images", images)
+            .card()
+            .composite()
+            .unwrap()
+    };
+    let layers = |composite: &Composite| composite.layers().collect::<Vec<_>>();
+
+    let full = card(
+        json!({"foil": "https://example.com/png/SVI_81_foil.png", "etch": "https://example.com/png/SVI_81_etch.png"}),
+        json!({}),
+    );
+    assert_eq!(layers(&full), [Layer::Front, Layer::Foil, Layer::Etch]);
+    assert_eq!(full.etch_blend(), Some(BlendMode::Overlay));
+
+    // an etched mask without an etch texture
+    let no_etch = card(
+        json!({}),
+        json!({"foil": "https://example.com/tex/SVI_81_foil.tex"}),
+    );
+    assert_eq!(layers(&no_etch), [Layer::Front, Layer::Foil]);
+    assert_eq!(no_etch.etch_blend(), None);
+
+    let front_only = card(json!({}), json!({}));
+    assert_eq!(layers(&front_only), [Layer::Front]);
+    assert_eq!(front_only.pattern_blend(), BlendMode::Screen);
+
+    assert!(Fixture::pokemon().card().composite().is_none());
+}
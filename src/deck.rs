@@ -0,0 +1,300 @@
+//! Deck construction rules: a deck has exactly 60 cards, at most four copies
+//! of each name other than basic energy, and at most one ACE SPEC and one
+//! Radiant Pokémon.
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt;
+
+use crate::{Card, CardTag, id::CardId, serde_name};
+
+/// The number of cards in a deck.
+pub const DECK_SIZE: u32 = 60;
+
+/// A deck, as the number of copies of each card by `cardID`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Deck<'a> {
+    counts: BTreeMap<CardId<'a>, u32>,
+}
+
+/// A deck construction rule that a [`Deck`] breaks.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Violation {
+    /// The deck doesn't have exactly [`DECK_SIZE`] cards.
+    Size(u32),
+    /// A `cardID` in the deck isn't one of the known cards.
+    UnknownCard(CardId<'static>),
+    /// The deck has more copies of cards with the same name than
+    /// [`Card::copy_limit`] allows.
+    TooManyCopies { name: String, count: u32, limit: u8 },
+    /// The deck has more than one card with a tag of which only one is
+    /// allowed, see [`Card::one_per_deck`].
+    OnePerDeck { tag: CardTag, count: u32 },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::Size(size) => {
+                write!(f, "deck has {size} cards, expected {DECK_SIZE}")
+            }
+            Violation::UnknownCard(card_id) => write!(f, "unknown card {card_id}"),
+            Violation::TooManyCopies { name, count, limit } => write!(
+                f,
+                "deck has {count} copies of {name:?}, expected at most {limit}"
+            ),
+            Violation::OnePerDeck { tag, count } => write!(
+                f,
+                "deck has {count} cards tagged {}, expected at most 1",
+                serde_name::variant(tag)
+            ),
+        }
+    }
+}
+
+impl<'a> Deck<'a> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `count` copies of the card with the given `cardID`.
+    pub fn add(&mut self, card_id: CardId<'a>, count: u32) {
+        let total = self.counts.entry(card_id).or_insert(0);
+        *total = total.saturating_add(count);
+    }
+
+    /// The number of copies of the card with the given `cardID`.
+    #[must_use]
+    pub fn count(&self, card_id: &str) -> u32 {
+        self.counts.get(card_id).copied().unwrap_or_default()
+    }
+
+    /// The total number of cards in the deck.
+    #[must_use]
+    pub fn size(&self) -> u32 {
+        self.counts
+            .values()
+            .fold(0, |size, count| size.saturating_add(*count))
+    }
+
+    /// Every `cardID` in the deck with its number of copies, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&CardId<'a>, u32)> {
+        self.counts.iter().map(|(card_id, count)| (card_id, *count))
+    }
+
+    /// Check the deck against the construction rules, looking up each
+    /// `cardID` in `cards`. Returns a violation for each rule that doesn't
+    /// hold.
+    #[must_use]
+    pub fn validate(&self, cards: &[Card<'_>]) -> Vec<Violation> {
+        let mut by_id = BTreeMap::new();
+        for card in cards {
+            by_id.entry(card.tcgl().card_id().as_str()).or_insert(card);
+        }
+
+        let mut violations = Vec::new();
+
+        let size = self.size();
+        if size != DECK_SIZE {
+            violations.push(Violation::Size(size));
+        }
+
+        let mut by_name = BTreeMap::<&str, (u32, u8)>::new();
+        let mut one_per_deck = Vec::<(&CardTag, u32)>::new();
+
+        for (card_id, count) in self.iter() {
+            let Some(card) = by_id.get(card_id.as_str()) else {
+                violations.push(Violation::UnknownCard(card_id.clone().into_owned()));
+                continue;
+            };
+
+            if let Some(limit) = card.copy_limit() {
+                let (total, min_limit) = by_name.entry(card.name()).or_insert((0, limit));
+                *total = total.saturating_add(count);
+                *min_limit = (*min_limit).min(limit);
+            }
+
+            if let Some(tag) = card.one_per_deck() {
+                match one_per_deck.iter_mut().find(|(other, _)| *other == tag) {
+                    Some((_, total)) => *total = total.saturating_add(count),
+                    None => one_per_deck.push((tag, count)),
+                }
+            }
+        }
+
+        for (name, (count, limit)) in by_name {
+            if count > u32::from(limit) {
+                violations.push(Violation::TooManyCopies {
+                    name: name.into(),
+                    count,
+                    limit,
+                });
+            }
+        }
+
+        for (tag, count) in one_per_deck {
+            if count > 1 {
+                violations.push(Violation::OnePerDeck {
+                    tag: tag.clone(),
+                    count,
+                });
+            }
+        }
+
+        violations
+    }
+}
+
+impl<'a> FromIterator<(CardId<'a>, u32)> for Deck<'a> {
+    fn from_iter<I: IntoIterator<Item = (CardId<'a>, u32)>>(iter: I) -> Self {
+        let mut deck = Deck::new();
+        for (card_id, count) in iter {
+            deck.add(card_id, count);
+        }
+        deck
+    }
+}
+
+#[test]
+fn construction_rules() {
+    use serde_json::json;
+
+    use crate::fixture::Fixture;
+
+    let cards = [
+        Fixture::item(),
+        // the same name as Nest Ball SVI_181
+        Fixture::item().id("PAF_84").number("084/091"),
+        Fixture::pokemon(),
+        Fixture::basic_energy(),
+        Fixture::item()
+            .with("subtype", json!("SUPPORTER"))
+            .with("name", json!("Cyrus ◇"))
+            .id("UPR_120")
+            .with("tags", json!(["PRISM_STAR"])),
+        Fixture::item()
+            .with("name", json!("Prime Catcher"))
+            .id("TEF_157")
+            .with("tags", json!(["ACE_SPEC"])),
+        Fixture::item()
+            .with("name", json!("Master Ball"))
+            .id("TEF_153")
+            .with("tags", json!(["ACE_SPEC"])),
+    ]
+    .map(|fixture| fixture.card());
+
+    let deck = |counts: &[(&'static str, u32)]| {
+        counts
+            .iter()
+            .map(|&(card_id, count)| (CardId::new(card_id), count))
+            .collect::<Deck>()
+    };
+
+    let legal = deck(&[("SVI_181", 2), ("PAF_84", 2), ("SVI_81", 4), ("SVE_1", 52)]);
+    assert_eq!(legal.size(), DECK_SIZE);
+    assert_eq!(legal.validate(&cards), []);
+
+    let illegal = deck(&[
+        ("SVI_181", 3),
+        ("PAF_84", 2),
+        ("UPR_120", 2),
+        ("TEF_157", 1),
+        ("TEF_153", 1),
+        ("SVE_1", 50),
+        ("SVI_999", 1),
+    ]);
+    assert_eq!(
+        illegal.validate(&cards),
+        [
+            Violation::UnknownCard(CardId::new("SVI_999")),
+            Violation::TooManyCopies {
+                name: "Cyrus ◇".into(),
+                count: 2,
+                limit: 1
+            },
+            Violation::TooManyCopies {
+                name: "Nest Ball".into(),
+                count: 5,
+                limit: 4
+            },
+            Violation::OnePerDeck {
+                tag: CardTag::AceSpec,
+                count: 2
+            },
+        ]
+    );
+    assert_eq!(
+        deck(&[("SVE_1", 61)]).validate(&cards),
+        [Violation::Size(61)]
+    );
+}
+
+#[test]
+fn edge_cases() {
+    use serde_json::json;
+
+    use crate::fixture::Fixture;
+
+    let radiant = |name: &str, card_id: &str| {
+        Fixture::pokemon()
+            .with("name", json!(name))
+            .id(card_id)
+            .with("tags", json!(["RADIANT"]))
+    };
+    let cards = [
+        Fixture::basic_energy(),
+        Fixture::basic_energy()
+            .with("name", json!("Basic Fire Energy"))
+            .with("types", json!(["FIRE"]))
+            .id("SVE_2"),
+        radiant("Radiant Greninja", "ASR_46"),
+        radiant("Radiant Charizard", "PGO_11"),
+        Fixture::item(),
+    ]
+    .map(|fixture| fixture.card());
+
+    let empty = Deck::new();
+    assert_eq!(empty.size(), 0);
+    assert_eq!(empty.validate(&cards), [Violation::Size(0)]);
+    assert_eq!(empty.validate(&[]), [Violation::Size(0)]);
+
+    // basic energy has no copy limit
+    let energy = Deck::from_iter([(CardId::new("SVE_1"), 30), (CardId::new("SVE_2"), 30)]);
+    assert_eq!(energy.validate(&cards), []);
+
+    // one Radiant Pokémon in total, even with different names
+    let mut radiants = Deck::from_iter([
+        (CardId::new("ASR_46"), 1),
+        (CardId::new("PGO_11"), 1),
+        (CardId::new("SVE_1"), 58),
+    ]);
+    assert_eq!(
+        radiants.validate(&cards),
+        [Violation::OnePerDeck {
+            tag: CardTag::Radiant,
+            count: 2
+        }]
+    );
+
+    // adding the same card again adds up its copies
+    radiants.add(CardId::new("SVI_181"), 2);
+    radiants.add(CardId::new("SVI_181"), 3);
+    assert_eq!(radiants.count("SVI_181"), 5);
+    assert_eq!(radiants.count("SVI_1"), 0);
+    assert_eq!(radiants.size(), 65);
+    assert_eq!(
+        radiants.validate(&cards),
+        [
+            Violation::Size(65),
+            Violation::TooManyCopies {
+                name: "Nest Ball".into(),
+                count: 5,
+                limit: 4
+            },
+            Violation::OnePerDeck {
+                tag: CardTag::Radiant,
+                count: 2
+            },
+        ]
+    );
+}
@@ -0,0 +1,127 @@
+//! The physical dimensions of cards, for renderers and sleeve or storage
+//! calculators.
+
+use crate::{Card, CardBack, CardSize};
+
+/// The width and height of a card in portrait orientation, stored in tenths
+/// of a millimetre so that sizes like 63.5 mm are exact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dimensions {
+    width: u16,
+    height: u16,
+}
+
+impl Dimensions {
+    /// Dimensions from a width and height in tenths of a millimetre, i.e.
+    /// `635` for 63.5 mm.
+    #[must_use]
+    pub const fn from_tenth_mm(width: u16, height: u16) -> Self {
+        Self { width, height }
+    }
+
+    /// The width in millimetres.
+    #[must_use]
+    pub fn width(&self) -> f32 {
+        f32::from(self.width) / 10.0
+    }
+
+    /// The height in millimetres.
+    #[must_use]
+    pub fn height(&self) -> f32 {
+        f32::from(self.height) / 10.0
+    }
+
+    /// The width in tenths of a millimetre.
+    #[must_use]
+    pub fn width_tenth_mm(&self) -> u16 {
+        self.width
+    }
+
+    /// The height in tenths of a millimetre.
+    #[must_use]
+    pub fn height_tenth_mm(&self) -> u16 {
+        self.height
+    }
+
+    /// The width divided by the height, i.e. for sizing a render.
+    #[must_use]
+    pub fn aspect_ratio(&self) -> f32 {
+        f32::from(self.width) / f32::from(self.height)
+    }
+
+    /// Whether a card of these dimensions fits inside `inner`, i.e. the inner
+    /// dimensions of a sleeve or a binder pocket.
+    #[must_use]
+    pub fn fits_in(&self, inner: Dimensions) -> bool {
+        self.width <= inner.width && self.height <= inner.height
+    }
+}
+
+impl CardSize {
+    /// The nominal dimensions of the card size, ignoring cutting tolerances.
+    ///
+    /// ```
+    /// # let size = serde_json::from_str::<malie::CardSize>(r#""STANDARD""#).unwrap();
+    /// let dimensions = size.dimensions();
+    ///
+    /// assert_eq!((dimensions.width(), dimensions.height()), (63.5, 88.9));
+    /// assert!((dimensions.aspect_ratio() - 0.714).abs() < 0.001);
+    /// ```
+    #[must_use]
+    pub fn dimensions(&self) -> Dimensions {
+        match self {
+            // 2.5 by 3.5 inches
+            CardSize::Standard => Dimensions::from_tenth_mm(635, 889),
+            // 5.75 by 8 inches
+            CardSize::Jumbo => Dimensions::from_tenth_mm(1461, 2032),
+        }
+    }
+}
+
+impl CardBack {
+    /// Whether the back is the one shared by every market, rather than the
+    /// back of the oldest Japanese printings.
+    #[must_use]
+    pub fn is_international(&self) -> bool {
+        match self {
+            CardBack::Pokemon1999 => true,
+            CardBack::PocketMonsters1996 => false,
+        }
+    }
+
+    /// The dimensions of the standard sized cards printed with the back. Both
+    /// backs were printed on cards of the same size, so cards with either
+    /// back fit the same sleeves.
+    #[must_use]
+    pub fn dimensions(&self) -> Dimensions {
+        match self {
+            CardBack::Pokemon1999 | CardBack::PocketMonsters1996 => CardSize::Standard.dimensions(),
+        }
+    }
+}
+
+impl Card<'_> {
+    #[must_use]
+    pub fn dimensions(&self) -> Dimensions {
+        self.size().dimensions()
+    }
+}
+
+#[test]
+fn sleeves() {
+    let standard_sleeve = Dimensions::from_tenth_mm(660, 910);
+    let standard = CardSize::Standard.dimensions();
+
+    assert_eq!(
+        (standard.width_tenth_mm(), standard.height_tenth_mm()),
+        (635, 889)
+    );
+    assert!((standard.width() - 63.5).abs() < f32::EPSILON);
+    assert!(standard.fits_in(standard_sleeve));
+    assert!(!CardSize::Jumbo.dimensions().fits_in(standard_sleeve));
+    assert!(
+        CardBack::PocketMonsters1996
+            .dimensions()
+            .fits_in(standard_sleeve)
+    );
+}
@@ -0,0 +1,259 @@
+//! Resolving the best available printing of a card from a collection of
+//! exports in several languages, following a chain of preferred languages,
+//! i.e. `es-419` → `es-ES` → `en-US`.
+//!
+//! ```
+//! use malie::{Lang, fallback::Printings, id::CardId};
+//!
+//! # let cards: [malie::Card; 0] = [];
+//! let printings = Printings::new(&cards);
+//! let chain = [Lang::Es419, Lang::EsEs, Lang::EnUs];
+//!
+//! if let Some(resolution) = printings.card(&CardId::new("SVI_181"), &chain) {
+//!     let name = resolution.name();
+//!     println!("{} ({:?})", name.value, name.lang);
+//! }
+//! ```
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::{
+    Card, Copyright, Lang, Rarity, RegulationMark,
+    id::{ArchetypeId, CardId},
+};
+
+/// An index of every printing in a collection by `cardID` and archetype.
+#[derive(Debug, Clone)]
+pub struct Printings<'c, 'a> {
+    by_card: BTreeMap<&'c CardId<'a>, Vec<&'c Card<'a>>>,
+    by_archetype: BTreeMap<ArchetypeId, Vec<&'c Card<'a>>>,
+}
+
+/// A value resolved from one of the printings of a card, with the language of
+/// the printing it was taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Resolved<'c, T> {
+    pub value: T,
+    pub lang: &'c Lang,
+}
+
+/// The printings of a card in each language of a chain, in order of
+/// preference.
+#[derive(Debug, Clone)]
+pub struct Resolution<'c, 'a> {
+    printings: Vec<&'c Card<'a>>,
+}
+
+impl<'c, 'a> Printings<'c, 'a> {
+    #[must_use]
+    pub fn new(cards: &'c [Card<'a>]) -> Self {
+        let mut by_card = BTreeMap::<_, Vec<_>>::new();
+        let mut by_archetype = BTreeMap::<_, Vec<_>>::new();
+
+        for card in cards {
+            let tcgl = card.tcgl();
+            by_card.entry(tcgl.card_id()).or_default().push(card);
+            by_archetype
+                .entry(tcgl.archetype_id)
+                .or_default()
+                .push(card);
+        }
+
+        Self {
+            by_card,
+            by_archetype,
+        }
+    }
+
+    /// Resolve the card with the given `cardID`, or `None` if it isn't
+    /// printed in any language of `chain`.
+    #[must_use]
+    pub fn card(&self, card_id: &CardId<'_>, chain: &[Lang]) -> Option<Resolution<'c, 'a>> {
+        Resolution::new(self.by_card.get(card_id)?, chain)
+    }
+
+    /// Resolve any card of the given archetype, or `None` if none is printed
+    /// in any language of `chain`. Within a language, the most recently
+    /// released printing is preferred.
+    #[must_use]
+    pub fn archetype(
+        &self,
+        archetype_id: ArchetypeId,
+        chain: &[Lang],
+    ) -> Option<Resolution<'c, 'a>> {
+        let mut printings = self.by_archetype.get(&archetype_id)?.clone();
+        printings.sort_by(|a, b| b.tcgl().reldate.cmp(&a.tcgl().reldate));
+
+        Resolution::new(&printings, chain)
+    }
+}
+
+impl<'c, 'a> Resolution<'c, 'a> {
+    fn new(printings: &[&'c Card<'a>], chain: &[Lang]) -> Option<Self> {
+        let printings = chain
+            .iter()
+            .flat_map(|lang| printings.iter().filter(move |card| card.lang() == lang))
+            .copied()
+            .collect::<Vec<_>>();
+
+        (!printings.is_empty()).then_some(Self { printings })
+    }
+
+    /// The printing in the most preferred language.
+    #[must_use]
+    pub fn card(&self) -> &'c Card<'a> {
+        self.printings[0]
+    }
+
+    /// The language of [`card`](Self::card).
+    #[must_use]
+    pub fn lang(&self) -> &'c Lang {
+        self.card().lang()
+    }
+
+    /// Every printing, in order of preference.
+    #[must_use]
+    pub fn printings(&self) -> &[&'c Card<'a>] {
+        &self.printings
+    }
+
+    /// Resolve a field from the first printing that has it, or `None` if no
+    /// printing does.
+    pub fn field<T>(&self, field: impl Fn(&'c Card<'a>) -> Option<T>) -> Option<Resolved<'c, T>> {
+        self.printings.iter().find_map(|card| {
+            Some(Resolved {
+                value: field(card)?,
+                lang: card.lang(),
+            })
+        })
+    }
+
+    #[must_use]
+    pub fn name(&self) -> Resolved<'c, &'c str> {
+        Resolved {
+            value: self.card().name(),
+            lang: self.lang(),
+        }
+    }
+
+    #[must_use]
+    pub fn copyright(&self) -> Option<Resolved<'c, &'c Copyright<'a>>> {
+        self.field(Card::copyright)
+    }
+
+    #[must_use]
+    pub fn rarity(&self) -> Option<Resolved<'c, &'c Rarity>> {
+        self.field(Card::rarity)
+    }
+
+    #[must_use]
+    pub fn regulation_mark(&self) -> Option<Resolved<'c, &'c RegulationMark>> {
+        self.field(Card::regulation_mark)
+    }
+}
+
+#[test]
+fn chain() {
+    use serde_json::json;
+
+    use crate::fixture::Fixture;
+
+    let card = |lang: &str, name: &str| {
+        Fixture::item()
+            .with("lang", json!(lang))
+            .with("name", json!(name))
+            .tcgl("archetypeID", json!("0x00000001"))
+    };
+
+    let cards = [
+        card("en-US", "Nest Ball")
+            .with("copyright", json!({"text": "©2023", "year": 2023}))
+            .card(),
+        card("es-ES", "Nido Ball").card(),
+    ];
+    let printings = Printings::new(&cards);
+
+    let resolution = printings
+        .card(
+            &CardId::new("SVI_181"),
+            &[Lang::Es419, Lang::EsEs, Lang::EnUs],
+        )
+        .unwrap();
+    assert_eq!(
+        resolution.name(),
+        Resolved {
+            value: "Nido Ball",
+            lang: &Lang::EsEs
+        }
+    );
+    assert_eq!(resolution.copyright().unwrap().lang, &Lang::EnUs);
+    assert_eq!(
+        printings
+            .archetype(ArchetypeId::new(1), &[Lang::EnUs])
+            .unwrap()
+            .name()
+            .value,
+        "Nest Ball"
+    );
+    assert!(
+        printings
+            .card(&CardId::new("SVI_181"), &[Lang::FrFr])
+            .is_none()
+    );
+}
+
+#[test]
+fn fall_through() {
+    use serde_json::json;
+
+    use crate::fixture::Fixture;
+
+    let cards = [
+        Fixture::item()
+            .with("lang", json!("de-DE"))
+            .with("name", json!("Nestball"))
+            .without("regulation_mark")
+            .card(),
+        Fixture::item().card(),
+        Fixture::item()
+            .id("PAL_181")
+            .number("181/193")
+            .reldate("2023-06-09")
+            .card(),
+    ];
+    let printings = Printings::new(&cards);
+    let nest_ball = CardId::new("SVI_181");
+
+    let resolution = printings
+        .card(&nest_ball, &[Lang::FrFr, Lang::DeDe, Lang::EnUs])
+        .unwrap();
+    assert_eq!(resolution.lang(), &Lang::DeDe);
+    assert_eq!(resolution.printings().len(), 2);
+    assert_eq!(
+        resolution.regulation_mark().unwrap(),
+        Resolved {
+            value: &RegulationMark::G,
+            lang: &Lang::EnUs
+        }
+    );
+    assert!(resolution.copyright().is_none());
+
+    assert!(printings.card(&nest_ball, &[]).is_none());
+    assert!(
+        printings
+            .card(&CardId::new("SVI_1"), &[Lang::EnUs])
+            .is_none()
+    );
+
+    // the most recent printing of the archetype
+    let archetype = printings
+        .archetype(cards[1].tcgl().archetype_id, &[Lang::EnUs, Lang::DeDe])
+        .unwrap();
+    assert_eq!(archetype.card().tcgl().card_id().as_str(), "PAL_181");
+    assert_eq!(archetype.printings().len(), 3);
+    assert!(
+        printings
+            .archetype(ArchetypeId::new(2), &[Lang::EnUs])
+            .is_none()
+    );
+}
@@ -0,0 +1,475 @@
+//! A C ABI for using the crate from native code.
+//!
+//! Enable the `ffi` feature to use this module. The C header is committed at
+//! `include/malie.h`. Building with the feature generates it again with
+//! [cbindgen] into `OUT_DIR`, and a test checks that the two match.
+//!
+//! A collection is parsed with [`malie_cards_parse`] and must be released
+//! with [`malie_cards_free`]. Every [`MalieCard`] and [`MalieStr`] obtained
+//! from a collection borrows from it, and is only valid until it is freed.
+//! When parsing fails, [`malie_last_error`] describes why.
+//!
+//! # Safety
+//!
+//! Every pointer passed to a function must either be null or have been
+//! returned by this library and not yet freed. Functions return null, an
+//! absent [`MalieStr`] or 0 when passed a null pointer.
+//!
+//! ```c
+//! MalieCards *cards = malie_cards_parse(json, json_len);
+//! if (cards) {
+//!     MalieCardIter *iter = malie_cards_iter(cards);
+//!     const MalieCard *card;
+//!     while ((card = malie_card_iter_next(iter))) {
+//!         MalieStr name = malie_card_name(card);
+//!         printf("%.*s\n", (int)name.len, (const char *)name.ptr);
+//!     }
+//!     malie_card_iter_free(iter);
+//!     malie_cards_free(cards);
+//! } else {
+//!     MalieStr error = malie_last_error();
+//!     fprintf(stderr, "%.*s\n", (int)error.len, (const char *)error.ptr);
+//! }
+//! ```
+//!
+//! [cbindgen]: https://github.com/mozilla/cbindgen
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{cell::RefCell, ptr, slice};
+
+use crate::{Card, serde_name};
+
+std::thread_local! {
+    /// Why the last call to [`malie_cards_parse`] on this thread failed, or
+    /// empty if it succeeded.
+    static LAST_ERROR: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// An owned collection of cards.
+pub struct MalieCards {
+    cards: Vec<MalieCard>,
+}
+
+/// A single card, borrowed from a [`MalieCards`].
+pub struct MalieCard {
+    card: Card<'static>,
+}
+
+/// An iterator over the cards in a [`MalieCards`].
+pub struct MalieCardIter<'a> {
+    cards: slice::Iter<'a, MalieCard>,
+}
+
+/// A borrowed UTF-8 string, which is *not* NUL-terminated.
+///
+/// Absent values are represented by a null `ptr` and a `len` of 0.
+#[repr(C)]
+pub struct MalieStr {
+    pub ptr: *const u8,
+    pub len: usize,
+}
+
+impl MalieStr {
+    const NONE: Self = Self {
+        ptr: ptr::null(),
+        len: 0,
+    };
+
+    fn new(s: &str) -> Self {
+        Self {
+            ptr: s.as_ptr(),
+            len: s.len(),
+        }
+    }
+
+    fn option(s: Option<&str>) -> Self {
+        s.map_or(Self::NONE, Self::new)
+    }
+}
+
+/// Call `f` with the card behind `card`, or return `default` if it is null.
+///
+/// # Safety
+///
+/// `card` must be null or point to a live [`MalieCard`].
+unsafe fn with_card<T>(card: *const MalieCard, default: T, f: impl FnOnce(&Card<'_>) -> T) -> T {
+    // SAFETY: guaranteed by the caller
+    match unsafe { card.as_ref() } {
+        Some(card) => f(&card.card),
+        None => default,
+    }
+}
+
+/// Parse the contents of an export file.
+///
+/// Returns null if `json` is not a valid export, and the reason is then
+/// available from [`malie_last_error`].
+///
+/// # Safety
+///
+/// `json` must point to `len` readable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_cards_parse(json: *const u8, len: usize) -> Option<Box<MalieCards>> {
+    let result = if json.is_null() {
+        Err(String::from("json is null"))
+    } else {
+        // SAFETY: guaranteed by the caller
+        let json = unsafe { slice::from_raw_parts(json, len) };
+        serde_json::from_slice::<Vec<Card>>(json).map_err(|error| error.to_string())
+    };
+
+    LAST_ERROR.with_borrow_mut(|last_error| match result {
+        Ok(cards) => {
+            last_error.clear();
+
+            Some(Box::new(MalieCards {
+                cards: cards
+                    .into_iter()
+                    .map(|card| MalieCard {
+                        card: card.into_owned(),
+                    })
+                    .collect(),
+            }))
+        }
+        Err(error) => {
+            *last_error = error;
+            None
+        }
+    })
+}
+
+/// Why the last call to [`malie_cards_parse`] on the current thread returned
+/// null, with the line and column the error occurred at, or an absent string
+/// if it succeeded.
+///
+/// The string is only valid until the next call to [`malie_cards_parse`] on
+/// the same thread, and must not be freed.
+#[unsafe(no_mangle)]
+pub extern "C" fn malie_last_error() -> MalieStr {
+    LAST_ERROR.with_borrow(|last_error| {
+        MalieStr::option(Some(last_error.as_str()).filter(|error| !error.is_empty()))
+    })
+}
+
+/// Free a collection returned by [`malie_cards_parse`].
+#[unsafe(no_mangle)]
+pub extern "C" fn malie_cards_free(cards: Option<Box<MalieCards>>) {
+    drop(cards);
+}
+
+/// The number of cards in the collection, or 0 if `cards` is null.
+///
+/// # Safety
+///
+/// `cards` must be null or a live collection.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_cards_len(cards: *const MalieCards) -> usize {
+    // SAFETY: guaranteed by the caller
+    unsafe { cards.as_ref() }.map_or(0, |cards| cards.cards.len())
+}
+
+/// The card at `index`, or null if it is out of bounds or `cards` is null.
+///
+/// # Safety
+///
+/// `cards` must be null or a live collection.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_cards_get(
+    cards: *const MalieCards,
+    index: usize,
+) -> *const MalieCard {
+    // SAFETY: guaranteed by the caller
+    unsafe { cards.as_ref() }
+        .and_then(|cards| cards.cards.get(index))
+        .map_or(ptr::null(), ptr::from_ref)
+}
+
+/// Iterate over the cards in the collection, or null if `cards` is null.
+///
+/// The iterator must be released with [`malie_card_iter_free`], before the
+/// collection is.
+///
+/// # Safety
+///
+/// `cards` must be null or a live collection.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_cards_iter(
+    cards: *const MalieCards,
+) -> Option<Box<MalieCardIter<'static>>> {
+    // SAFETY: guaranteed by the caller, and the caller frees the iterator
+    // before the collection
+    let cards = unsafe { cards.as_ref() }?;
+
+    Some(Box::new(MalieCardIter {
+        cards: cards.cards.iter(),
+    }))
+}
+
+/// The next card, or null once every card has been returned or if `iter` is
+/// null.
+///
+/// # Safety
+///
+/// `iter` must be null or a live iterator.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_card_iter_next(iter: *mut MalieCardIter<'_>) -> *const MalieCard {
+    // SAFETY: guaranteed by the caller
+    unsafe { iter.as_mut() }
+        .and_then(|iter| iter.cards.next())
+        .map_or(ptr::null(), ptr::from_ref)
+}
+
+/// Free an iterator returned by [`malie_cards_iter`].
+#[unsafe(no_mangle)]
+pub extern "C" fn malie_card_iter_free(iter: Option<Box<MalieCardIter<'_>>>) {
+    drop(iter);
+}
+
+/// The `card_type` of the card, i.e. `POKEMON`.
+///
+/// # Safety
+///
+/// `card` must be null or a card of a live collection.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_card_type(card: *const MalieCard) -> MalieStr {
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        with_card(card, MalieStr::NONE, |card| {
+            MalieStr::new(card.type_tags().0)
+        })
+    }
+}
+
+/// The `subtype` of the card, i.e. `ITEM`, if it has one.
+///
+/// # Safety
+///
+/// `card` must be null or a card of a live collection.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_card_subtype(card: *const MalieCard) -> MalieStr {
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        with_card(card, MalieStr::NONE, |card| {
+            MalieStr::option(card.type_tags().1)
+        })
+    }
+}
+
+/// The name of the card.
+///
+/// # Safety
+///
+/// `card` must be null or a card of a live collection.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_card_name(card: *const MalieCard) -> MalieStr {
+    // SAFETY: guaranteed by the caller
+    unsafe { with_card(card, MalieStr::NONE, |card| MalieStr::new(card.name())) }
+}
+
+/// The language of the card, i.e. `en-US`.
+///
+/// # Safety
+///
+/// `card` must be null or a card of a live collection.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_card_lang(card: *const MalieCard) -> MalieStr {
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        with_card(card, MalieStr::NONE, |card| {
+            MalieStr::new(serde_name::variant(card.lang()))
+        })
+    }
+}
+
+/// The set icon of the card, i.e. `SVI`.
+///
+/// # Safety
+///
+/// `card` must be null or a card of a live collection.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_card_set_icon(card: *const MalieCard) -> MalieStr {
+    // SAFETY: guaranteed by the caller
+    unsafe { with_card(card, MalieStr::NONE, |card| MalieStr::new(card.set_icon())) }
+}
+
+/// The full collector number of the card, i.e. `244/198`.
+///
+/// # Safety
+///
+/// `card` must be null or a card of a live collection.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_card_collector_number(card: *const MalieCard) -> MalieStr {
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        with_card(card, MalieStr::NONE, |card| {
+            MalieStr::new(&card.collector_number().full)
+        })
+    }
+}
+
+/// The rarity designation of the card, i.e. `DOUBLE_RARE`, if it has one.
+///
+/// # Safety
+///
+/// `card` must be null or a card of a live collection.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_card_rarity(card: *const MalieCard) -> MalieStr {
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        with_card(card, MalieStr::NONE, |card| {
+            MalieStr::option(
+                card.rarity()
+                    .map(|rarity| serde_name::variant(&rarity.designation)),
+            )
+        })
+    }
+}
+
+/// The regulation mark of the card, i.e. `G`, if it has one.
+///
+/// # Safety
+///
+/// `card` must be null or a card of a live collection.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_card_regulation_mark(card: *const MalieCard) -> MalieStr {
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        with_card(card, MalieStr::NONE, |card| {
+            MalieStr::option(card.regulation_mark().map(serde_name::variant))
+        })
+    }
+}
+
+/// The HP of the card, or 0 if it has none.
+///
+/// # Safety
+///
+/// `card` must be null or a card of a live collection.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_card_hp(card: *const MalieCard) -> u16 {
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        with_card(card, 0, |card| {
+            card.hp().map_or(0, core::num::NonZeroU16::get)
+        })
+    }
+}
+
+/// The number of energy types the card has.
+///
+/// # Safety
+///
+/// `card` must be null or a card of a live collection.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_card_energy_types_len(card: *const MalieCard) -> usize {
+    // SAFETY: guaranteed by the caller
+    unsafe { with_card(card, 0, |card| card.types().len()) }
+}
+
+/// The energy type at `index`, i.e. `GRASS`, or a null string if it is out
+/// of bounds.
+///
+/// # Safety
+///
+/// `card` must be null or a card of a live collection.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_card_energy_type(card: *const MalieCard, index: usize) -> MalieStr {
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        with_card(card, MalieStr::NONE, |card| {
+            MalieStr::option(card.types().get(index).map(serde_name::variant))
+        })
+    }
+}
+
+/// The TCGL `cardID` of the card, i.e. `SVI_244`.
+///
+/// # Safety
+///
+/// `card` must be null or a card of a live collection.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn malie_card_id(card: *const MalieCard) -> MalieStr {
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        with_card(card, MalieStr::NONE, |card| {
+            MalieStr::new(card.tcgl().card_id().as_str())
+        })
+    }
+}
+
+#[test]
+fn iterate() {
+    let json = crate::fixture::export([&crate::fixture::Fixture::basic_energy()]);
+
+    let str = |s: MalieStr| {
+        // SAFETY: borrowed from `cards`, which is still alive
+        unsafe { core::str::from_utf8(slice::from_raw_parts(s.ptr, s.len)).unwrap() }
+    };
+
+    // SAFETY: `json` is a valid slice, and every pointer passed below is
+    // either null or still alive
+    unsafe {
+        let owned = malie_cards_parse(json.as_ptr(), json.len()).unwrap();
+        assert!(malie_last_error().ptr.is_null());
+        let cards = ptr::from_ref(&*owned);
+        assert_eq!(malie_cards_len(cards), 1);
+        assert!(malie_cards_get(cards, 1).is_null());
+
+        let mut iter = malie_cards_iter(cards).unwrap();
+        let card = malie_card_iter_next(&raw mut *iter);
+        assert_eq!(str(malie_card_id(card)), "SVE_1");
+        assert_eq!(str(malie_card_energy_type(card, 0)), "GRASS");
+        assert!(malie_card_regulation_mark(card).ptr.is_null());
+        assert!(malie_card_iter_next(&raw mut *iter).is_null());
+        malie_card_iter_free(Some(iter));
+        malie_cards_free(Some(owned));
+
+        assert_eq!(malie_cards_len(ptr::null()), 0);
+        assert!(malie_cards_get(ptr::null(), 0).is_null());
+        assert!(malie_cards_iter(ptr::null()).is_none());
+        assert!(malie_card_iter_next(ptr::null_mut()).is_null());
+        assert!(malie_card_name(ptr::null()).ptr.is_null());
+        assert_eq!(malie_card_hp(ptr::null()), 0);
+        malie_card_iter_free(None);
+        malie_cards_free(None);
+    }
+}
+
+#[test]
+fn last_error() {
+    let str = |s: MalieStr| {
+        // SAFETY: borrowed from `LAST_ERROR`, which isn't changed before use
+        unsafe { core::str::from_utf8(slice::from_raw_parts(s.ptr, s.len)).unwrap() }
+    };
+
+    // SAFETY: every slice is valid
+    unsafe {
+        assert!(malie_cards_parse(b"[1]".as_ptr(), 3).is_none());
+        assert_eq!(
+            str(malie_last_error()),
+            "invalid type: integer `1`, expected internally tagged enum Card at line 1 column 2"
+        );
+
+        assert!(malie_cards_parse(ptr::null(), 0).is_none());
+        assert_eq!(str(malie_last_error()), "json is null");
+
+        let cards = malie_cards_parse(b"[]".as_ptr(), 2).unwrap();
+        assert!(malie_last_error().ptr.is_null());
+        assert_eq!(malie_cards_len(ptr::from_ref(&*cards)), 0);
+        malie_cards_free(Some(cards));
+    }
+}
+
+#[test]
+fn header_is_up_to_date() {
+    let generated = include_str!(concat!(env!("OUT_DIR"), "/malie.h"));
+    assert!(
+        generated == include_str!("../include/malie.h"),
+        "include/malie.h is out of date, copy it from {}/malie.h",
+        env!("OUT_DIR")
+    );
+}
@@ -0,0 +1,177 @@
+//! Cards for tests, built from a minimal export entry with every required
+//! field filled in, and the real exports in `SOURCES_DIR`.
+
+use alloc::{format, string::String, vec::Vec};
+
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::Card;
+
+/// A single entry of an export, with setters for the fields tests vary.
+#[derive(Debug, Clone)]
+pub(crate) struct Fixture(Value);
+
+impl Fixture {
+    /// Nest Ball, `SVI_181`.
+    pub(crate) fn item() -> Self {
+        Self(json!({
+            "card_type": "TRAINER",
+            "subtype": "ITEM",
+            "name": "Nest Ball",
+            "lang": "en-US",
+            "size": "STANDARD",
+            "back": "POKEMON_1999",
+            "regulation_mark": "G",
+            "text": [
+                {"kind": "EFFECT", "name": "Item", "text": "Search your deck for a Basic Pokémon and put it onto your Bench."},
+                {"kind": "REMINDER", "text": "You may play any number of Item cards during your turn."}
+            ],
+            "ext": {"tcgl": {"archetypeID": "0x00000181", "reldate": "2023-03-31 00:00:00+00:00"}}
+        }))
+        .id("SVI_181")
+        .number("181/198")
+    }
+
+    /// Miraidon ex, `SVI_81`.
+    pub(crate) fn pokemon() -> Self {
+        Self(json!({
+            "card_type": "POKEMON",
+            "name": "Miraidon ex",
+            "lang": "en-US",
+            "size": "STANDARD",
+            "back": "POKEMON_1999",
+            "regulation_mark": "G",
+            "tags": ["EX_LOWER"],
+            "stage": "BASIC",
+            "hp": 220,
+            "types": ["LIGHTNING"],
+            "text": [
+                {"kind": "ATTACK", "name": "Photon Blaster", "cost": ["LIGHTNING", "LIGHTNING", "COLORLESS"], "damage": {"amount": 220}},
+                {"kind": "RULE_BOX", "name": "Pokémon ex rule", "text": "When your Pokémon ex is Knocked Out, your opponent takes 2 Prize cards."}
+            ],
+            "ext": {"tcgl": {"archetypeID": "0x0001beef", "reldate": "2023-03-31 00:00:00+00:00"}}
+        }))
+        .id("SVI_81")
+        .number("081/198")
+    }
+
+    /// Basic Grass Energy, `SVE_1`.
+    pub(crate) fn basic_energy() -> Self {
+        Self(json!({
+            "card_type": "ENERGY",
+            "subtype": "BASIC",
+            "name": "Basic Grass Energy",
+            "lang": "en-US",
+            "size": "STANDARD",
+            "back": "POKEMON_1999",
+            "types": ["GRASS"],
+            "ext": {"tcgl": {"archetypeID": "0x00000001", "reldate": "2023-03-31 00:00:00+00:00"}}
+        }))
+        .id("SVE_1")
+        .number("001")
+    }
+
+    /// Set a field of the card.
+    pub(crate) fn with(mut self, key: &str, value: Value) -> Self {
+        self.0[key] = value;
+        self
+    }
+
+    /// Remove a field of the card.
+    pub(crate) fn without(mut self, key: &str) -> Self {
+        if let Some(object) = self.0.as_object_mut() {
+            object.remove(key);
+        }
+        self
+    }
+
+    /// Set a field of `ext.tcgl`.
+    pub(crate) fn tcgl(mut self, key: &str, value: Value) -> Self {
+        self.0["ext"]["tcgl"][key] = value;
+        self
+    }
+
+    /// Set the `cardID` and every field derived from it: the set icon, the
+    /// other IDs and the image URLs.
+    pub(crate) fn id(mut self, card_id: &str) -> Self {
+        let (set, number) = card_id.rsplit_once('_').unwrap();
+        let name = self.0["name"].as_str().unwrap().replace(' ', "_");
+        let lang_code = self.0["lang"].as_str().unwrap()[..2].to_uppercase();
+        let padded = match number.parse::<u16>() {
+            Ok(number) => format!("{number:03}"),
+            Err(_) => String::from(number),
+        };
+
+        self.0["set_icon"] = json!(set);
+        self.0["images"] = json!({"tcgl": {
+            "jpg": {"front": format!("https://example.com/jpg/{card_id}.jpg")},
+            "png": {"front": format!("https://example.com/png/{card_id}.png")},
+            "tex": {"front": format!("https://example.com/tex/{card_id}.tex")}
+        }});
+        self.tcgl("cardID", json!(card_id))
+            .tcgl("key", json!(format!("{set}_{padded}_R_{lang_code}")))
+            .tcgl("longFormID", json!(format!("{name}_{card_id}")))
+    }
+
+    /// Set the collector number from how it's printed, i.e. `181/198`.
+    pub(crate) fn number(self, full: &str) -> Self {
+        let (numerator, denominator) = match full.split_once('/') {
+            Some((numerator, denominator)) => (numerator, Some(denominator)),
+            None => (full, None),
+        };
+        // without any set code printed before the number, i.e. `SV1S 061`
+        let numeric = numerator
+            .rsplit(' ')
+            .next()
+            .unwrap()
+            .chars()
+            .filter(char::is_ascii_digit)
+            .collect::<String>()
+            .parse::<u16>()
+            .unwrap_or(1);
+
+        let mut collector_number =
+            json!({"full": full, "numerator": numerator, "numeric": numeric});
+        if let Some(denominator) = denominator {
+            collector_number["denominator"] = json!(denominator);
+        }
+        self.with("collector_number", collector_number)
+    }
+
+    /// Set the release date, i.e. `2023-03-31`.
+    pub(crate) fn reldate(self, date: &str) -> Self {
+        self.tcgl("reldate", json!(format!("{date} 00:00:00+00:00")))
+    }
+
+    pub(crate) fn value(&self) -> &Value {
+        &self.0
+    }
+
+    pub(crate) fn card(&self) -> Card<'static> {
+        Card::deserialize(&self.0).unwrap().into_owned()
+    }
+}
+
+/// The JSON of an export of `fixtures`.
+pub(crate) fn export<'f>(fixtures: impl IntoIterator<Item = &'f Fixture>) -> String {
+    Value::Array(
+        fixtures
+            .into_iter()
+            .map(|fixture| fixture.value().clone())
+            .collect(),
+    )
+    .to_string()
+}
+
+/// Every export in `SOURCES_DIR`, by file name.
+pub(crate) fn exports() -> Vec<(String, String)> {
+    std::fs::read_dir(std::env::var("SOURCES_DIR").unwrap())
+        .unwrap()
+        .map(|dirent| {
+            let path = dirent.unwrap().path();
+            let file_name = path.file_name().unwrap().to_string_lossy().into_owned();
+            (file_name, std::fs::read_to_string(path).unwrap())
+        })
+        .collect()
+}
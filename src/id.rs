@@ -0,0 +1,259 @@
+//! Typed identifiers for the IDs in [`Tcgl`], usable as map keys and parsable
+//! from command line arguments.
+//!
+//! ```
+//! use malie::id::{ArchetypeId, CardKey, LongFormId};
+//!
+//! let archetype_id = "0x0001beef".parse::<ArchetypeId>().unwrap();
+//! assert_eq!(archetype_id.get(), 0x0001_beef);
+//! assert_eq!(archetype_id.to_string(), "0x0001beef");
+//!
+//! let key = CardKey::new("SVI_244_R_EN");
+//! assert_eq!(key.set(), Some("SVI"));
+//! assert_eq!(key.lang_code(), Some("EN"));
+//!
+//! let long_form_id = LongFormId::new("Miraidon_ex_SVI_244");
+//! assert_eq!(long_form_id.name(), Some("Miraidon_ex"));
+//! assert_eq!(long_form_id.card_id().unwrap().number(), Some("244"));
+//! ```
+
+use alloc::{borrow::Cow, string::String};
+use core::{borrow::Borrow, convert::Infallible, fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{Tcgl, u32_hex};
+
+/// The ID shared by every printing of the same card, regardless of set or
+/// language. Formatted as `0x`-prefixed hex, i.e. `0x0001beef`.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct ArchetypeId(#[serde(with = "crate::u32_hex")] u32);
+
+impl ArchetypeId {
+    #[must_use]
+    pub const fn new(id: u32) -> Self {
+        Self(id)
+    }
+
+    #[must_use]
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+}
+
+impl From<u32> for ArchetypeId {
+    fn from(id: u32) -> Self {
+        Self(id)
+    }
+}
+
+impl From<ArchetypeId> for u32 {
+    fn from(id: ArchetypeId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for ArchetypeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{:08x}", u32_hex::HEX_ENCODING_PREFIX, self.0)
+    }
+}
+
+impl FromStr for ArchetypeId {
+    type Err = ParseArchetypeIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        u32_hex::parse(s).map(Self).ok_or(ParseArchetypeIdError)
+    }
+}
+
+/// An error parsing an [`ArchetypeId`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseArchetypeIdError;
+
+impl fmt::Display for ParseArchetypeIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected hex encoded u32 bytes, 0x-prefixed")
+    }
+}
+
+impl core::error::Error for ParseArchetypeIdError {}
+
+macro_rules! string_id {
+    ($($(#[$attr:meta])* $ty:ident;)+) => {$(
+        $(#[$attr])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $ty<'a>(#[serde(borrow)] Cow<'a, str>);
+
+        impl<'a> $ty<'a> {
+            #[must_use]
+            pub fn new(id: impl Into<Cow<'a, str>>) -> Self {
+                Self(id.into())
+            }
+
+            #[must_use]
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            #[must_use]
+            pub fn into_owned(self) -> $ty<'static> {
+                $ty(Cow::Owned(self.0.into_owned()))
+            }
+        }
+
+        impl fmt::Display for $ty<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl FromStr for $ty<'static> {
+            type Err = Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self(Cow::Owned(String::from(s))))
+            }
+        }
+
+        impl Borrow<str> for $ty<'_> {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+    )+};
+}
+
+string_id! {
+    /// The ID of a card within a set, shared by its printings in every
+    /// language, i.e. `SVI_244`.
+    CardId;
+    /// The ID of a single printing of a card, i.e. `SVI_244_R_EN`.
+    CardKey;
+    /// A human readable version of the [`CardId`], prefixed with the name of
+    /// the card, i.e. `Miraidon_ex_SVI_244`.
+    LongFormId;
+}
+
+/// Deserialize an owned ID, for types that must implement
+/// [`DeserializeOwned`](serde::de::DeserializeOwned) and so can't borrow it.
+pub(crate) fn deserialize_owned<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr<Err = Infallible>,
+{
+    let Ok(id) = String::deserialize(deserializer)?.parse();
+    Ok(id)
+}
+
+impl CardId<'_> {
+    /// The set code, i.e. `SVI`.
+    #[must_use]
+    pub fn set(&self) -> Option<&str> {
+        self.0.rsplit_once('_').map(|(set, _)| set)
+    }
+
+    /// The collector number without any padding, i.e. `244` or `TG05`.
+    #[must_use]
+    pub fn number(&self) -> Option<&str> {
+        self.0.rsplit_once('_').map(|(_, number)| number)
+    }
+}
+
+impl CardKey<'_> {
+    /// The `set`, `number`, `variant` and `lang_code` of the key.
+    fn split(&self) -> Option<[&str; 4]> {
+        let mut parts = self.0.rsplitn(4, '_');
+        let lang_code = parts.next()?;
+        let variant = parts.next()?;
+        let number = parts.next()?;
+        let set = parts.next()?;
+        Some([set, number, variant, lang_code])
+    }
+
+    /// The set code, i.e. `SVI`.
+    #[must_use]
+    pub fn set(&self) -> Option<&str> {
+        self.split().map(|[set, ..]| set)
+    }
+
+    /// The zero padded collector number, i.e. `013`.
+    #[must_use]
+    pub fn number(&self) -> Option<&str> {
+        self.split().map(|[_, number, ..]| number)
+    }
+
+    /// The printed variant, i.e. `R`.
+    #[must_use]
+    pub fn variant(&self) -> Option<&str> {
+        self.split().map(|[_, _, variant, _]| variant)
+    }
+
+    /// The upper case language code, i.e. `EN`. The code is shared by every
+    /// regional variant of a language, so it doesn't identify a [`Lang`].
+    ///
+    /// [`Lang`]: crate::Lang
+    #[must_use]
+    pub fn lang_code(&self) -> Option<&str> {
+        self.split().map(|[.., lang_code]| lang_code)
+    }
+}
+
+impl LongFormId<'_> {
+    /// The name of the card with spaces replaced by `_`, i.e. `Miraidon_ex`.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        let mut parts = self.0.rsplitn(3, '_');
+        parts.nth(2)
+    }
+
+    /// The [`CardId`] the ID ends with.
+    #[must_use]
+    pub fn card_id(&self) -> Option<CardId<'_>> {
+        let name = self.name()?;
+        Some(CardId::new(&self.0[name.len() + 1..]))
+    }
+}
+
+impl<'a> Tcgl<'a> {
+    #[must_use]
+    pub fn archetype_id(&self) -> ArchetypeId {
+        self.archetype_id
+    }
+
+    #[must_use]
+    pub fn card_id(&self) -> &CardId<'a> {
+        &self.card_id
+    }
+
+    #[must_use]
+    pub fn key(&self) -> &CardKey<'a> {
+        &self.key
+    }
+
+    #[must_use]
+    pub fn long_form_id(&self) -> &LongFormId<'a> {
+        &self.long_form_id
+    }
+}
+
+#[test]
+fn map_keys() {
+    use alloc::collections::BTreeMap;
+
+    let counts = BTreeMap::from([(CardId::new("SVI_13"), 1), (CardId::new("SVE_1"), 2)]);
+    assert_eq!(counts.get("SVE_1"), Some(&2));
+
+    assert_eq!(CardId::new("SVE_1").set(), Some("SVE"));
+    assert_eq!(CardKey::new("SVE_001").variant(), None);
+    assert_eq!(
+        LongFormId::new("Basic_Grass_Energy_SVE_1").name(),
+        Some("Basic_Grass_Energy")
+    );
+    assert!("0x0001be".parse::<ArchetypeId>().is_err());
+    assert!("0001beef".parse::<ArchetypeId>().is_err());
+}
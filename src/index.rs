@@ -0,0 +1,141 @@
+//! The index of the exports, listing the export file of every set in every
+//! language, and the only source of set names.
+//!
+//! ```json
+//! {"en-US": {"Scarlet & Violet": {"path": "v0.1.9.12/svi_en-US.json", "hash": "..."}}}
+//! ```
+//!
+//! ```
+//! use malie::{index::Index, set::Sets};
+//!
+//! # let cards: [malie::Card; 0] = [];
+//! # let json = "{}";
+//! let index = serde_json::from_str::<Index>(json).unwrap();
+//! let mut sets = Sets::new(&cards);
+//! sets.add_names(&index);
+//! ```
+
+use alloc::{borrow::Cow, collections::BTreeMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Lang;
+
+/// The export files of every set, by language and set name.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Index<'a> {
+    #[serde(borrow)]
+    langs: BTreeMap<Lang, BTreeMap<Cow<'a, str>, Entry<'a>>>,
+}
+
+/// The export file of a single set in a single language.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Entry<'a> {
+    #[serde(borrow)]
+    path: Cow<'a, str>,
+    #[serde(borrow)]
+    hash: Cow<'a, str>,
+}
+
+impl<'a> Index<'a> {
+    /// Every set in the index as its language, name and export file, in
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Lang, &str, &Entry<'a>)> {
+        self.langs.iter().flat_map(|(lang, sets)| {
+            sets.iter()
+                .map(move |(name, entry)| (lang, name.as_ref(), entry))
+        })
+    }
+
+    /// The export file of the set `name` in `lang`.
+    #[must_use]
+    pub fn get(&self, lang: &Lang, name: &str) -> Option<&Entry<'a>> {
+        self.langs.get(lang)?.get(name)
+    }
+}
+
+impl Entry<'_> {
+    /// The path of the file relative to the root of the exports, i.e.
+    /// `v0.1.9.12/svi_en-US.json`.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The MD5 hash of the file.
+    #[must_use]
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    /// The version of the exporter the file was exported with, i.e.
+    /// `v0.1.9.12`.
+    #[must_use]
+    pub fn version(&self) -> Option<&str> {
+        self.path.split_once('/').map(|(version, _)| version)
+    }
+
+    /// The name of the file, i.e. `svi_en-US.json`.
+    #[must_use]
+    pub fn file_name(&self) -> &str {
+        self.path
+            .rsplit_once('/')
+            .map_or(&self.path, |(_, file_name)| file_name)
+    }
+
+    /// The code of the set, which its file is named after in lower case, i.e.
+    /// `svi`.
+    #[must_use]
+    pub fn set_code(&self) -> Option<&str> {
+        self.file_name().rsplit_once('_').map(|(code, _)| code)
+    }
+}
+
+#[test]
+fn names() {
+    use serde_json::json;
+
+    use crate::{fixture::Fixture, market::Market, set::Sets};
+
+    let json = json!({
+        "en-US": {
+            "Scarlet & Violet": {"path": "v0.1.9.12/svi_en-US.json", "hash": "0f343b0931126a20f133d67c2b018a3b"},
+            "Paldean Fates": {"path": "v0.1.9.12/paf_en-US.json", "hash": "5ea7a1c1d2a4ad1d0d8bc1d1c1e0f8a6"}
+        },
+        "fr-FR": {
+            "Écarlate et Violet": {"path": "v0.1.9.12/svi_fr-FR.json", "hash": "b026324c6904b2a9cb4b88d6d61c81d1"}
+        },
+        "ja-JP": {
+            "スカーレットex": {"path": "v0.1.9.12/sv1s_ja-JP.json", "hash": "26ab0db90d72e28ad0ba1e22ee510510"}
+        }
+    })
+    .to_string();
+    let index = serde_json::from_str::<Index>(&json).unwrap();
+
+    let entry = index.get(&Lang::EnUs, "Scarlet & Violet").unwrap();
+    assert_eq!(entry.version(), Some("v0.1.9.12"));
+    assert_eq!(entry.file_name(), "svi_en-US.json");
+    assert_eq!(entry.set_code(), Some("svi"));
+    assert_eq!(index.iter().count(), 4);
+
+    let cards = [
+        Fixture::item().card(),
+        Fixture::item()
+            .with("lang", json!("ja-JP"))
+            .id("SV1S_61")
+            .card(),
+    ];
+    let mut sets = Sets::new(&cards);
+    // Paldean Fates isn't in the cards
+    assert_eq!(sets.add_names(&index), 3);
+
+    let svi = sets.get(Market::International, "SVI").unwrap();
+    assert_eq!(svi.name(&Lang::EnUs), Some("Scarlet & Violet"));
+    assert_eq!(svi.name(&Lang::FrFr), Some("Écarlate et Violet"));
+    assert_eq!(
+        sets.get(Market::Japan, "SV1S").unwrap().name(&Lang::JaJp),
+        Some("スカーレットex")
+    );
+}
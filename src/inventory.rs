@@ -0,0 +1,490 @@
+//! A personal collection of owned cards.
+
+use alloc::{
+    borrow::ToOwned,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+use core::fmt::{self, Write};
+
+use serde::{
+    Deserialize, Serialize,
+    de::{IntoDeserializer, value},
+};
+
+use crate::{Card, FoilMask, Lang, id::CardId, serde_name, table};
+
+/// The condition grade of a physical card.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[serde(deny_unknown_fields, rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Condition {
+    Mint,
+    #[default]
+    NearMint,
+    LightlyPlayed,
+    ModeratelyPlayed,
+    HeavilyPlayed,
+    Damaged,
+}
+
+/// What an [`Inventory`] counts copies of: a card, in a single printed
+/// variant, language and condition.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Key {
+    #[serde(rename = "cardID", deserialize_with = "crate::id::deserialize_owned")]
+    card_id: CardId<'static>,
+    lang: Lang,
+    /// `None` for a non-foil printing, otherwise the foil mask of the
+    /// printing, i.e. [`FoilMask::Reverse`] for a reverse holo.
+    foil: Option<FoilMask>,
+    condition: Condition,
+}
+
+impl Key {
+    #[must_use]
+    pub fn new(
+        card_id: CardId<'_>,
+        lang: Lang,
+        foil: Option<FoilMask>,
+        condition: Condition,
+    ) -> Self {
+        Self {
+            card_id: card_id.into_owned(),
+            lang,
+            foil,
+            condition,
+        }
+    }
+
+    /// A key for `card` as it is printed, in the given condition.
+    #[must_use]
+    pub fn for_card(card: &Card<'_>, condition: Condition) -> Self {
+        Self::new(
+            card.tcgl().card_id().clone(),
+            card.lang().clone(),
+            card.foil().map(|foil| foil.mask.clone()),
+            condition,
+        )
+    }
+
+    #[must_use]
+    pub fn card_id(&self) -> &CardId<'static> {
+        &self.card_id
+    }
+
+    #[must_use]
+    pub fn lang(&self) -> &Lang {
+        &self.lang
+    }
+
+    #[must_use]
+    pub fn foil(&self) -> Option<&FoilMask> {
+        self.foil.as_ref()
+    }
+
+    #[must_use]
+    pub fn condition(&self) -> Condition {
+        self.condition
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Entry {
+    #[serde(rename = "cardID", deserialize_with = "crate::id::deserialize_owned")]
+    card_id: CardId<'static>,
+    lang: Lang,
+    foil: Option<FoilMask>,
+    condition: Condition,
+    count: u32,
+}
+
+/// Owned quantities of cards, keyed on `cardID`, variant, language and
+/// condition.
+///
+/// Serializes as a list of entries:
+///
+/// ```json
+/// [{"cardID": "SVI_181", "lang": "en-US", "foil": "REVERSE", "condition": "NEAR_MINT", "count": 2}]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(from = "Vec<Entry>", into = "Vec<Entry>")]
+pub struct Inventory {
+    counts: BTreeMap<Key, u32>,
+}
+
+/// A difference in the count of a single key between two inventories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change<'a> {
+    pub key: &'a Key,
+    pub before: u32,
+    pub after: u32,
+}
+
+/// An error reading an inventory from CSV.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CsvError {
+    /// The header row is missing or not the expected one.
+    Header,
+    /// A row could not be parsed, by the line it starts on, 1-indexed
+    /// including the header.
+    Row(usize),
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Header => write!(f, "expected the header {CSV_HEADER:?}"),
+            CsvError::Row(line) => write!(f, "invalid row on line {line}"),
+        }
+    }
+}
+
+impl core::error::Error for CsvError {}
+
+const CSV_HEADER: &str = "card_id,lang,foil,condition,count";
+
+impl Inventory {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of copies owned of `key`.
+    #[must_use]
+    pub fn get(&self, key: &Key) -> u32 {
+        self.counts.get(key).copied().unwrap_or(0)
+    }
+
+    /// Add `count` copies of `key`.
+    pub fn add(&mut self, key: Key, count: u32) {
+        if count != 0 {
+            let owned = self.counts.entry(key).or_insert(0);
+            *owned = owned.saturating_add(count);
+        }
+    }
+
+    /// Remove up to `count` copies of `key`, returning how many were removed.
+    pub fn remove(&mut self, key: &Key, count: u32) -> u32 {
+        let Some(owned) = self.counts.get_mut(key) else {
+            return 0;
+        };
+
+        let removed = count.min(*owned);
+        *owned -= removed;
+        if *owned == 0 {
+            self.counts.remove(key);
+        }
+        removed
+    }
+
+    /// Every key with at least one copy owned, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, u32)> {
+        self.counts.iter().map(|(key, count)| (key, *count))
+    }
+
+    /// The total number of copies owned of each `cardID`, across every
+    /// variant, language and condition. This is the input expected by
+    /// [`completion`](crate::completion::completion).
+    #[must_use]
+    pub fn counts_by_card(&self) -> BTreeMap<CardId<'_>, u32> {
+        let mut counts = BTreeMap::new();
+        for (key, count) in &self.counts {
+            let total = counts
+                .entry(CardId::new(key.card_id().as_str()))
+                .or_insert(0_u32);
+            *total = total.saturating_add(*count);
+        }
+        counts
+    }
+
+    /// Add every copy in `other` to this inventory.
+    pub fn merge(&mut self, other: &Inventory) {
+        for (key, count) in other.iter() {
+            self.add(key.clone(), count);
+        }
+    }
+
+    /// Every key whose count differs between this inventory and `other`.
+    #[must_use]
+    pub fn diff<'a>(&'a self, other: &'a Inventory) -> Vec<Change<'a>> {
+        self.counts
+            .keys()
+            .chain(other.counts.keys())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .filter_map(|key| {
+                let before = self.get(key);
+                let after = other.get(key);
+                (before != after).then_some(Change { key, before, after })
+            })
+            .collect()
+    }
+
+    /// Every key that doesn't match one of `cards` by `cardID` and language.
+    #[must_use]
+    pub fn validate(&self, cards: &[Card<'_>]) -> Vec<&Key> {
+        let known = cards
+            .iter()
+            .map(|card| (card.tcgl().card_id.as_str(), card.lang()))
+            .collect::<BTreeSet<_>>();
+
+        self.counts
+            .keys()
+            .filter(|key| !known.contains(&(key.card_id().as_str(), key.lang())))
+            .collect()
+    }
+
+    /// Write the inventory as CSV, with a header row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `out` fails.
+    pub fn write_csv<W: Write>(&self, out: &mut W) -> fmt::Result {
+        writeln!(out, "{CSV_HEADER}")?;
+
+        for (key, count) in self.iter() {
+            table::write_cell(out, key.card_id().as_str(), ',')?;
+            writeln!(
+                out,
+                ",{},{},{},{count}",
+                serde_name::variant(key.lang()),
+                key.foil().map_or("", serde_name::variant),
+                serde_name::variant(&key.condition()),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Read an inventory written by [`write_csv`](Self::write_csv). Rows for
+    /// the same key are added together.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header is missing, or a row is invalid.
+    pub fn read_csv(csv: &str) -> Result<Self, CsvError> {
+        let mut rows = csv_rows(csv);
+        if rows.next().map(|(_, row)| row.trim_end()) != Some(CSV_HEADER) {
+            return Err(CsvError::Header);
+        }
+
+        let mut inventory = Inventory::new();
+        for (line, row) in rows {
+            if row.trim().is_empty() {
+                continue;
+            }
+
+            let (key, count) = parse_row(row).ok_or(CsvError::Row(line))?;
+            inventory.add(key, count);
+        }
+
+        Ok(inventory)
+    }
+}
+
+/// Every row of `csv` with the line it starts on, 1-indexed. A row ends at
+/// the first line break outside of quotes, so a quoted cell may span several
+/// lines.
+fn csv_rows(csv: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut rest = csv;
+    let mut line = 1;
+
+    core::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let mut quoted = false;
+        let end = rest
+            .char_indices()
+            .find(|&(_, c)| {
+                if c == '"' {
+                    quoted = !quoted;
+                }
+                c == '\n' && !quoted
+            })
+            .map_or(rest.len(), |(i, _)| i);
+
+        let row = &rest[..end];
+        let start = line;
+        line += row.matches('\n').count() + 1;
+        rest = rest.get(end + 1..).unwrap_or_default();
+
+        Some((start, row.strip_suffix('\r').unwrap_or(row)))
+    })
+}
+
+fn parse_row(line: &str) -> Option<(Key, u32)> {
+    fn variant<'de, T: Deserialize<'de>>(s: &'de str) -> Option<T> {
+        T::deserialize(IntoDeserializer::<value::Error>::into_deserializer(s)).ok()
+    }
+
+    // the card id is the only cell which may be quoted
+    let (card_id, rest) = if let Some(quoted) = line.strip_prefix('"') {
+        let mut card_id = String::new();
+        let mut chars = quoted.char_indices();
+        let end = loop {
+            let (i, c) = chars.next()?;
+            if c != '"' {
+                card_id.push(c);
+            } else if quoted[i + 1..].starts_with('"') {
+                // an escaped quote
+                card_id.push('"');
+                chars.next();
+            } else {
+                break i;
+            }
+        };
+        (card_id, quoted[end + 1..].strip_prefix(',')?)
+    } else {
+        let (card_id, rest) = line.split_once(',')?;
+        (card_id.to_owned(), rest)
+    };
+
+    let mut cells = rest.trim_end().split(',');
+    let lang = variant(cells.next()?)?;
+    let foil = match cells.next()? {
+        "" => None,
+        foil => Some(variant(foil)?),
+    };
+    let condition = variant(cells.next()?)?;
+    let count = cells.next()?.parse().ok()?;
+
+    if cells.next().is_some() {
+        return None;
+    }
+
+    Some((Key::new(CardId::new(card_id), lang, foil, condition), count))
+}
+
+impl From<Vec<Entry>> for Inventory {
+    fn from(entries: Vec<Entry>) -> Self {
+        let mut inventory = Inventory::new();
+        for entry in entries {
+            inventory.add(
+                Key::new(entry.card_id, entry.lang, entry.foil, entry.condition),
+                entry.count,
+            );
+        }
+        inventory
+    }
+}
+
+impl From<Inventory> for Vec<Entry> {
+    fn from(inventory: Inventory) -> Self {
+        inventory
+            .counts
+            .into_iter()
+            .map(|(key, count)| Entry {
+                card_id: key.card_id,
+                lang: key.lang,
+                foil: key.foil,
+                condition: key.condition,
+                count,
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn merge_diff_and_csv() {
+    let reverse = Key::new(
+        CardId::new("SVI_181"),
+        Lang::EnUs,
+        Some(FoilMask::Reverse),
+        Condition::NearMint,
+    );
+    let plain = Key::new(
+        CardId::new("SVI_181"),
+        Lang::EnUs,
+        None,
+        Condition::LightlyPlayed,
+    );
+
+    let mut before = Inventory::new();
+    before.add(reverse.clone(), 1);
+
+    let mut after = before.clone();
+    let mut more = Inventory::new();
+    more.add(reverse.clone(), 2);
+    more.add(plain.clone(), 1);
+    after.merge(&more);
+
+    assert_eq!(after.counts_by_card()["SVI_181"], 4);
+    assert_eq!(
+        before.diff(&after),
+        [
+            Change {
+                key: &plain,
+                before: 0,
+                after: 1
+            },
+            Change {
+                key: &reverse,
+                before: 1,
+                after: 3
+            },
+        ]
+    );
+
+    let mut csv = String::new();
+    after.write_csv(&mut csv).unwrap();
+    assert_eq!(
+        csv,
+        "card_id,lang,foil,condition,count\n\
+         SVI_181,en-US,,LIGHTLY_PLAYED,1\n\
+         SVI_181,en-US,REVERSE,NEAR_MINT,3\n"
+    );
+    assert_eq!(Inventory::read_csv(&csv), Ok(after.clone()));
+    assert_eq!(
+        Inventory::read_csv(&csv.replace('\n', "\r\n")),
+        Ok(after.clone())
+    );
+
+    let json = serde_json::to_string(&after).unwrap();
+    assert_eq!(serde_json::from_str::<Inventory>(&json).unwrap(), after);
+}
+
+#[test]
+fn csv_quoting() {
+    let mut inventory = Inventory::new();
+    for card_id in [
+        "SVI_181",
+        "SVI,181",
+        "SVI \"181\"",
+        "SVI\n181",
+        "SVI\r\n\"181\",\n",
+    ] {
+        inventory.add(
+            Key::new(CardId::new(card_id), Lang::EnUs, None, Condition::NearMint),
+            1,
+        );
+    }
+
+    let mut csv = String::new();
+    inventory.write_csv(&mut csv).unwrap();
+    assert_eq!(Inventory::read_csv(&csv), Ok(inventory));
+
+    assert_eq!(
+        Inventory::read_csv("card_id,lang,foil,condition\n"),
+        Err(CsvError::Header)
+    );
+    assert_eq!(Inventory::read_csv(""), Err(CsvError::Header));
+    // the row after a multi-line cell is reported on its own line
+    assert_eq!(
+        Inventory::read_csv(
+            "card_id,lang,foil,condition,count\n\"SVI\n181\",en-US,,NEAR_MINT,1\nSVI_181,en-US,,NEAR_MINT\n"
+        ),
+        Err(CsvError::Row(4))
+    );
+    // an unterminated quote runs to the end
+    assert_eq!(
+        Inventory::read_csv("card_id,lang,foil,condition,count\n\"SVI_181,en-US,,NEAR_MINT,1\n"),
+        Err(CsvError::Row(2))
+    );
+}
@@ -0,0 +1,885 @@
+//! Loading exports from JSON, with errors that locate the card and field that
+//! failed to deserialize. [`from_str_partial`] skips any cards that fail
+//! rather than failing the whole export, and [`stream_slice`] and, with the
+//! `std` feature, [`stream_reader`] deserialize one card at a time.
+//!
+//! ```
+//! let error = malie::json::from_str(r#"[{"card_type": "TRAINER", "subtype": "ITEM"}]"#)
+//!     .unwrap_err();
+//!
+//! assert_eq!(error.index(), Some(0));
+//! assert_eq!(error.path(), "[0]");
+//! ```
+
+#[cfg(feature = "std")]
+use alloc::{boxed::Box, rc::Rc};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use core::cell::Cell;
+use core::{fmt, iter::FusedIterator};
+#[cfg(feature = "std")]
+use std::io;
+
+use serde::Deserialize;
+#[cfg(feature = "std")]
+use serde_json::{StreamDeserializer, de::IoRead};
+use serde_json::{Value, value::RawValue};
+
+use crate::{
+    Ability, Attack, BasicEnergy, Card, Effect, Item, Pokemon, Reminder, RuleBox, SpecialEnergy,
+    Stadium, Supporter, TextBox, Tool,
+};
+
+/// An error loading an export, with the location of the card that failed.
+#[derive(Debug)]
+pub struct Error {
+    index: Option<usize>,
+    card_id: Option<String>,
+    path: String,
+    inner: serde_json::Error,
+}
+
+impl Error {
+    /// The index of the card that failed, or `None` if the export isn't a
+    /// well-formed JSON array.
+    #[must_use]
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// The `cardID` of the card that failed, if it could be read.
+    #[must_use]
+    pub fn card_id(&self) -> Option<&str> {
+        self.card_id.as_deref()
+    }
+
+    /// The path to the field that failed, i.e. `[112].text[2].cost[0]`, or
+    /// `.` if the export isn't a well-formed JSON array.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The underlying error, with the line and column it occurred at.
+    #[must_use]
+    pub fn inner(&self) -> &serde_json::Error {
+        &self.inner
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(index) = self.index {
+            write!(f, "card {index}")?;
+            if let Some(card_id) = &self.card_id {
+                write!(f, " ({card_id})")?;
+            }
+            write!(f, " at {}: ", self.path)?;
+        }
+
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.inner)
+    }
+}
+
+/// Deserialize an export, borrowing from `input` wherever possible.
+///
+/// # Errors
+///
+/// Returns an error if `input` isn't a JSON array of cards, locating the first
+/// card that failed.
+pub fn from_str(input: &str) -> Result<Vec<Card<'_>>, Error> {
+    serde_json::from_str(input).map_err(|inner| locate(input, inner))
+}
+
+/// Locate the card that failed, by deserializing each card separately.
+pub(crate) fn locate(input: &str, inner: serde_json::Error) -> Error {
+    let Ok(cards) = serde_json::from_str::<Vec<Value>>(input) else {
+        return Error {
+            index: None,
+            card_id: None,
+            path: String::from("."),
+            inner,
+        };
+    };
+
+    match cards
+        .iter()
+        .enumerate()
+        .find(|(_, card)| Card::deserialize(*card).is_err())
+    {
+        Some((index, card)) => locate_card(index, card, inner),
+        None => Error {
+            index: None,
+            card_id: None,
+            path: String::from("."),
+            inner,
+        },
+    }
+}
+
+/// Locate the field of the card at `index` that failed, given the input
+/// starting at the card.
+fn locate_element(index: usize, input: &[u8], inner: serde_json::Error) -> Error {
+    match serde_json::Deserializer::from_slice(input)
+        .into_iter::<Value>()
+        .next()
+    {
+        Some(Ok(card)) => locate_card(index, &card, inner),
+        _ => Error {
+            index: Some(index),
+            card_id: None,
+            path: format!("[{index}]"),
+            inner,
+        },
+    }
+}
+
+/// Locate the field of the card at `index` that failed.
+fn locate_card(index: usize, card: &Value, inner: serde_json::Error) -> Error {
+    let path = match card_path(card) {
+        Some(path) if !path.is_empty() => format!("[{index}].{path}"),
+        _ => format!("[{index}]"),
+    };
+
+    Error {
+        index: Some(index),
+        card_id: card
+            .pointer("/ext/tcgl/cardID")
+            .and_then(Value::as_str)
+            .map(String::from),
+        path,
+        inner,
+    }
+}
+
+/// The cards of an export that deserialized, and the errors of those that
+/// didn't.
+#[derive(Debug)]
+pub struct Partial<'a> {
+    cards: Vec<Card<'a>>,
+    errors: Vec<CardError<'a>>,
+}
+
+impl<'a> Partial<'a> {
+    /// Every card that deserialized, in the order of the export.
+    #[must_use]
+    pub fn cards(&self) -> &[Card<'a>] {
+        &self.cards
+    }
+
+    /// Every card that failed, in the order of the export.
+    #[must_use]
+    pub fn errors(&self) -> &[CardError<'a>] {
+        &self.errors
+    }
+
+    #[must_use]
+    pub fn into_parts(self) -> (Vec<Card<'a>>, Vec<CardError<'a>>) {
+        (self.cards, self.errors)
+    }
+}
+
+/// A card that failed to deserialize, with its raw JSON.
+///
+/// The line and column of the [`error`](Self::error) are relative to the
+/// start of [`raw`](Self::raw), not of the export, as each card is
+/// deserialized from its raw JSON separately.
+#[derive(Debug)]
+pub struct CardError<'a> {
+    index: usize,
+    raw: &'a str,
+    error: Error,
+}
+
+impl<'a> CardError<'a> {
+    /// The index of the card in the export.
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The JSON of the card, as it appears in the export.
+    #[must_use]
+    pub fn raw(&self) -> &'a str {
+        self.raw
+    }
+
+    #[must_use]
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+}
+
+impl fmt::Display for CardError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl core::error::Error for CardError<'_> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Deserialize each card of an export separately, skipping any that fail
+/// rather than failing the whole export.
+///
+/// # Errors
+///
+/// Returns an error if `input` isn't a JSON array.
+pub fn from_str_partial(input: &str) -> Result<Partial<'_>, Error> {
+    let raws = serde_json::from_str::<Vec<&RawValue>>(input).map_err(|inner| Error {
+        index: None,
+        card_id: None,
+        path: String::from("."),
+        inner,
+    })?;
+
+    let mut cards = Vec::with_capacity(raws.len());
+    let mut errors = Vec::new();
+    for (index, raw) in raws.into_iter().enumerate() {
+        let raw = raw.get();
+        match serde_json::from_str(raw) {
+            Ok(card) => cards.push(card),
+            Err(inner) => {
+                let error = match serde_json::from_str::<Value>(raw) {
+                    Ok(card) => locate_card(index, &card, inner),
+                    Err(inner) => Error {
+                        index: Some(index),
+                        card_id: None,
+                        path: format!("[{index}]"),
+                        inner,
+                    },
+                };
+                errors.push(CardError { index, raw, error });
+            }
+        }
+    }
+
+    Ok(Partial { cards, errors })
+}
+
+/// Deserialize the cards of an export one at a time, borrowing from `input`
+/// wherever possible, without holding every card in memory.
+///
+/// The iterator stops after the first error. The line and column of an error
+/// are relative to the start of the card that failed.
+#[must_use]
+pub fn stream_slice(input: &[u8]) -> SliceStream<'_> {
+    SliceStream {
+        input,
+        pos: 0,
+        index: 0,
+        next: Next::Open,
+    }
+}
+
+/// Deserialize the cards of an export one at a time from `reader`, without
+/// holding every card in memory.
+///
+/// The reader is read a byte at a time, so it should be buffered, i.e. with a
+/// [`BufReader`](std::io::BufReader). The iterator stops after the first
+/// error. The line and column of an error in a card are relative to the start
+/// of the card that failed.
+#[cfg(feature = "std")]
+#[must_use]
+pub fn stream_reader<R: io::Read>(reader: R) -> ReaderStream<R> {
+    let next = Rc::new(Cell::new(Next::Open));
+
+    ReaderStream {
+        cards: serde_json::Deserializer::from_reader(Elements {
+            reader,
+            depth: 0,
+            string: false,
+            escape: false,
+            next: Rc::clone(&next),
+        })
+        .into_iter(),
+        index: 0,
+        next,
+        done: false,
+    }
+}
+
+/// What is expected next in the array of an export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Next {
+    /// The `[` opening the array.
+    Open,
+    /// The first card, or the `]` closing an empty array.
+    First,
+    /// A card following a `,`.
+    Card,
+    /// The `,` or `]` following a card.
+    Separator,
+    /// Nothing, as the array is closed or an error occurred.
+    Done,
+}
+
+impl Next {
+    /// Whether the array is open, so an error is at the index of a card.
+    fn in_array(self) -> bool {
+        matches!(self, Next::First | Next::Card | Next::Separator)
+    }
+}
+
+/// An iterator of the cards of an export, borrowing from a byte slice.
+#[derive(Debug)]
+pub struct SliceStream<'a> {
+    input: &'a [u8],
+    pos: usize,
+    index: usize,
+    next: Next,
+}
+
+impl<'a> SliceStream<'a> {
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\n' | b'\t' | b'\r') = self.input.get(self.pos) {
+            self.pos += 1;
+        }
+    }
+
+    fn syntax(&mut self, message: &str) -> Error {
+        let index = self.next.in_array().then_some(self.index);
+        self.next = Next::Done;
+
+        Error {
+            index,
+            card_id: None,
+            path: index_path(index),
+            inner: serde::de::Error::custom(message),
+        }
+    }
+
+    fn card(&mut self) -> Result<Card<'a>, Error> {
+        let input = &self.input[self.pos..];
+        let mut cards = serde_json::Deserializer::from_slice(input).into_iter::<Card<'a>>();
+
+        match cards.next() {
+            Some(Ok(card)) => {
+                self.pos += cards.byte_offset();
+                self.index += 1;
+                self.next = Next::Separator;
+                Ok(card)
+            }
+            Some(Err(inner)) => {
+                self.next = Next::Done;
+                Err(locate_element(self.index, input, inner))
+            }
+            None => Err(self.syntax("expected a card")),
+        }
+    }
+}
+
+impl<'a> Iterator for SliceStream<'a> {
+    type Item = Result<Card<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.skip_whitespace();
+
+            match (self.next, self.input.get(self.pos)) {
+                (Next::Done, _) => return None,
+                (Next::Open, Some(b'[')) => {
+                    self.pos += 1;
+                    self.next = Next::First;
+                }
+                (Next::Open, _) => return Some(Err(self.syntax("expected `[`"))),
+                (Next::First | Next::Separator, Some(b']')) => {
+                    self.pos += 1;
+                    self.next = Next::Done;
+                    self.skip_whitespace();
+                    if self.pos < self.input.len() {
+                        return Some(Err(self.syntax("trailing characters")));
+                    }
+                    return None;
+                }
+                (Next::Separator, Some(b',')) => {
+                    self.pos += 1;
+                    self.next = Next::Card;
+                }
+                (Next::First | Next::Card, Some(_)) => return Some(self.card()),
+                (Next::First | Next::Card | Next::Separator, None) => {
+                    return Some(Err(self.syntax("expected `]`")));
+                }
+                (Next::Separator, Some(_)) => {
+                    return Some(Err(self.syntax("expected `,` or `]`")));
+                }
+            }
+        }
+    }
+}
+
+impl FusedIterator for SliceStream<'_> {}
+
+/// An iterator of the cards of an export, read from a reader.
+#[cfg(feature = "std")]
+pub struct ReaderStream<R: io::Read> {
+    /// Each card is read whole before it's deserialized, so that an error
+    /// can be located the same as in [`SliceStream`].
+    cards: StreamDeserializer<'static, IoRead<Elements<R>>, Box<RawValue>>,
+    index: usize,
+    /// Shared with the [`Elements`] being read.
+    next: Rc<Cell<Next>>,
+    done: bool,
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read> fmt::Debug for ReaderStream<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReaderStream")
+            .field("index", &self.index)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read> Iterator for ReaderStream<R> {
+    type Item = Result<Card<'static>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.cards.next()? {
+            Ok(raw) => match serde_json::from_str::<Card>(raw.get()) {
+                Ok(card) => {
+                    self.index += 1;
+                    Some(Ok(card.into_owned()))
+                }
+                Err(inner) => {
+                    self.done = true;
+                    Some(Err(locate_element(self.index, raw.get().as_bytes(), inner)))
+                }
+            },
+            Err(inner) => {
+                self.done = true;
+                let index = self.next.get().in_array().then_some(self.index);
+                Some(Err(Error {
+                    index,
+                    card_id: None,
+                    path: index_path(index),
+                    inner,
+                }))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read> FusedIterator for ReaderStream<R> {}
+
+/// A reader of the cards in an array as a stream of whitespace separated
+/// cards, by blanking out the `[`, `,` and `]` of the array itself.
+#[cfg(feature = "std")]
+struct Elements<R> {
+    reader: R,
+    depth: usize,
+    string: bool,
+    escape: bool,
+    /// What is expected next in the array itself.
+    next: Rc<Cell<Next>>,
+}
+
+#[cfg(feature = "std")]
+impl<R> Elements<R> {
+    fn scan(&mut self, byte: &mut u8) -> io::Result<()> {
+        let invalid = |message| Err(io::Error::new(io::ErrorKind::InvalidData, message));
+
+        if self.escape {
+            self.escape = false;
+            return Ok(());
+        }
+        if self.string {
+            match byte {
+                b'\\' => self.escape = true,
+                b'"' => self.string = false,
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        match (self.depth, *byte) {
+            (_, b' ' | b'\n' | b'\t' | b'\r') => {}
+            (0, b'[') if self.next.get() == Next::Open => {
+                *byte = b' ';
+                self.depth = 1;
+                self.next.set(Next::First);
+            }
+            (0, _) if self.next.get() == Next::Open => return invalid("expected `[`"),
+            (0, _) => return invalid("trailing characters"),
+            (1, b'{') if self.next.get() != Next::Separator => self.depth = 2,
+            (1, b',') if self.next.get() == Next::Separator => {
+                *byte = b' ';
+                self.next.set(Next::Card);
+            }
+            (1, b']') if self.next.get() != Next::Card => {
+                *byte = b' ';
+                self.depth = 0;
+                self.next.set(Next::Done);
+            }
+            (1, _) if self.next.get() == Next::Separator => return invalid("expected `,` or `]`"),
+            (1, _) => return invalid("expected a card"),
+            (_, b'"') => self.string = true,
+            (_, b'{' | b'[') => self.depth += 1,
+            (_, b'}' | b']') => {
+                self.depth -= 1;
+                if self.depth == 1 {
+                    self.next.set(Next::Separator);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read> io::Read for Elements<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.reader.read(buf)?;
+        if len == 0 && !buf.is_empty() && self.next.get() != Next::Done {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                if self.next.get() == Next::Open {
+                    "expected `[`"
+                } else {
+                    "expected `]`"
+                },
+            ));
+        }
+
+        for byte in &mut buf[..len] {
+            self.scan(byte)?;
+        }
+        Ok(len)
+    }
+}
+
+/// The path to the card at `index`, or to the export itself if `index` is
+/// `None`.
+fn index_path(index: Option<usize>) -> String {
+    index.map_or_else(|| String::from("."), |index| format!("[{index}]"))
+}
+
+/// The path to the field of `value` that failed to deserialize as `T`, or
+/// `None` if it didn't fail.
+fn trace<'v, T: Deserialize<'v>>(value: &'v Value) -> Option<serde_path_to_error::Path> {
+    serde_path_to_error::deserialize::<_, T>(value)
+        .err()
+        .map(|error| error.path().clone())
+}
+
+/// `value` without the keys used as tags by internally tagged enums, which
+/// the variant itself doesn't expect.
+fn untagged(value: &Value, tags: &[&str]) -> Option<Value> {
+    let mut object = value.as_object()?.clone();
+    for tag in tags {
+        object.remove(*tag);
+    }
+    Some(Value::Object(object))
+}
+
+/// The path to the field of `card` that failed, relative to the card.
+///
+/// Internally tagged enums are deserialized from a buffer, which hides the
+/// path within them, so each tagged enum is followed by hand to the variant
+/// it is tagged as.
+fn card_path(card: &Value) -> Option<String> {
+    let tag = |key| card.get(key).and_then(Value::as_str);
+
+    let path = match (tag("card_type")?, tag("subtype")) {
+        ("POKEMON", _) => trace::<Pokemon>(&untagged(card, &["card_type"])?),
+        (card_type, Some(subtype)) => {
+            let variant = untagged(card, &["card_type", "subtype"])?;
+            match (card_type, subtype) {
+                ("TRAINER", "ITEM") => trace::<Item>(&variant),
+                ("TRAINER", "SUPPORTER") => trace::<Supporter>(&variant),
+                ("TRAINER", "TOOL") => trace::<Tool>(&variant),
+                ("TRAINER", "STADIUM") => trace::<Stadium>(&variant),
+                ("ENERGY", "BASIC") => trace::<BasicEnergy>(&variant),
+                ("ENERGY", "SPECIAL") => trace::<SpecialEnergy>(&variant),
+                _ => None,
+            }
+        }
+        _ => None,
+    }?;
+
+    let mut segments = path.iter();
+    if let (
+        Some(serde_path_to_error::Segment::Map { key }),
+        Some(&serde_path_to_error::Segment::Seq { index }),
+        None,
+    ) = (segments.next(), segments.next(), segments.next())
+        && key == "text"
+        && let Some(text_path) = card
+            .get("text")
+            .and_then(|text| text.get(index))
+            .and_then(text_path)
+    {
+        return Some(format!("text[{index}].{text_path}"));
+    }
+
+    Some(path.to_string()).filter(|path| path != ".")
+}
+
+/// The path to the field of a [`Text`](crate::Text) that failed.
+fn text_path(text: &Value) -> Option<String> {
+    let variant = untagged(text, &["kind"])?;
+
+    let path = match text.get("kind").and_then(Value::as_str)? {
+        "ATTACK" => trace::<Attack>(&variant),
+        "ABILITY" => trace::<Ability>(&variant),
+        "RULE_BOX" => trace::<RuleBox>(&variant),
+        "EFFECT" => trace::<Effect>(&variant),
+        "TEXT_BOX" => trace::<TextBox>(&variant),
+        "REMINDER" => trace::<Reminder>(&variant),
+        _ => None,
+    }?;
+
+    Some(path.to_string()).filter(|path| path != ".")
+}
+
+#[cfg(test)]
+fn fixtures() -> Vec<Value> {
+    use crate::fixture::Fixture;
+
+    [
+        Fixture::item(),
+        Fixture::pokemon().id("SVI_244").number("244/198"),
+        Fixture::basic_energy(),
+    ]
+    .iter()
+    .map(|fixture| fixture.value().clone())
+    .collect()
+}
+
+#[test]
+fn path() {
+    let mut cards = fixtures();
+    cards[1]["text"][0]["cost"][0] = Value::from("PLASMA");
+    let input = serde_json::to_string_pretty(&cards).unwrap();
+
+    let error = from_str(&input).unwrap_err();
+    assert_eq!(error.index(), Some(1));
+    assert_eq!(error.card_id(), Some("SVI_244"));
+    assert_eq!(error.path(), "[1].text[0].cost[0]");
+    assert!(
+        error
+            .to_string()
+            .contains("expected one of `FREE`, `GRASS`")
+    );
+}
+
+#[test]
+fn every_variant_path() {
+    use serde_json::json;
+
+    use crate::fixture::Fixture;
+
+    let trainer = |subtype: &str| Fixture::item().with("subtype", json!(subtype));
+    let cards = [
+        Fixture::pokemon(),
+        Fixture::item(),
+        trainer("SUPPORTER"),
+        trainer("TOOL"),
+        trainer("STADIUM"),
+        Fixture::basic_energy(),
+        Fixture::basic_energy()
+            .with("subtype", json!("SPECIAL"))
+            .without("types")
+            .with("text", json!([])),
+    ];
+    for card in cards {
+        card.card();
+        let broken = card.with("size", json!("TINY"));
+        assert_eq!(card_path(broken.value()).as_deref(), Some("size"));
+    }
+
+    let texts = [
+        (
+            json!({"kind": "ATTACK", "name": "Tackle", "cost": ["PLASMA"]}),
+            "cost[0]",
+        ),
+        (json!({"kind": "ABILITY", "name": 1, "text": ""}), "name"),
+        (json!({"kind": "RULE_BOX", "name": "", "text": 1}), "text"),
+        (json!({"kind": "EFFECT", "name": "", "text": 1}), "text"),
+        (json!({"kind": "TEXT_BOX", "text": 1}), "text"),
+        (json!({"kind": "REMINDER", "text": 1}), "text"),
+    ];
+    for (text, path) in texts {
+        let card = Fixture::pokemon().with("text", json!([text]));
+        assert_eq!(card_path(card.value()), Some(format!("text[0].{path}")));
+    }
+}
+
+#[test]
+fn partial() {
+    let mut cards = fixtures();
+    let len = cards.len();
+    cards[1]["text"][0]["cost"][0] = Value::from("PLASMA");
+    cards[2]["card_type"] = Value::from("ITEM");
+    let input = serde_json::to_string_pretty(&cards).unwrap();
+
+    let partial = from_str_partial(&input).unwrap();
+    assert_eq!(partial.cards().len(), len - 2);
+    assert_eq!(
+        partial
+            .errors()
+            .iter()
+            .map(CardError::index)
+            .collect::<Vec<_>>(),
+        [1, 2]
+    );
+    assert_eq!(partial.errors()[0].error().path(), "[1].text[0].cost[0]");
+    assert_eq!(
+        serde_json::from_str::<Value>(partial.errors()[1].raw()).unwrap(),
+        cards[2]
+    );
+
+    // the line is within the raw card, rather than the whole export
+    let raw = partial.errors()[1].raw();
+    let start_line = input[..input.find(raw).unwrap()].lines().count();
+    let line = partial.errors()[1].error().inner().line();
+    assert!((1..=raw.lines().count()).contains(&line));
+    assert!(line < start_line);
+
+    assert!(from_str_partial("{}").is_err());
+}
+
+#[test]
+fn stream() {
+    let input = Value::Array(fixtures()).to_string();
+    let cards = from_str(&input).unwrap();
+
+    let streamed = stream_slice(input.as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(streamed, cards);
+    assert!(
+        input
+            .as_bytes()
+            .as_ptr_range()
+            .contains(&streamed[0].tcgl().card_id.as_str().as_ptr())
+    );
+
+    for (input, index) in [
+        ("", None),
+        ("{}", None),
+        ("[", Some(0)),
+        ("[1]", Some(0)),
+        ("[[]]", Some(0)),
+        ("[] []", None),
+    ] {
+        let error = stream_slice(input.as_bytes())
+            .find_map(Result::err)
+            .unwrap();
+        assert_eq!(
+            (error.index(), error.path()),
+            (index, index_path(index).as_str())
+        );
+    }
+    for input in ["[]", " [ ] "] {
+        assert_eq!(stream_slice(input.as_bytes()).count(), 0);
+    }
+
+    let mut cards = fixtures();
+    cards[2]["card_type"] = Value::from("ITEM");
+    let input = serde_json::to_string(&cards).unwrap();
+    let errors = stream_slice(input.as_bytes())
+        .filter_map(Result::err)
+        .collect::<Vec<_>>();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].index(), Some(2));
+    assert_eq!(stream_slice(input.as_bytes()).count(), 3);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn stream_read() {
+    let input = Value::Array(fixtures()).to_string();
+    let cards = from_str(&input).unwrap();
+
+    let read = stream_reader(std::io::BufReader::new(input.as_bytes()))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(read, cards);
+
+    for (input, index) in [
+        ("", None),
+        ("{}", None),
+        ("[", Some(0)),
+        ("[1]", Some(0)),
+        ("[[]]", Some(0)),
+        ("[] []", None),
+    ] {
+        let error = stream_reader(input.as_bytes())
+            .find_map(Result::err)
+            .unwrap();
+        assert_eq!(
+            (error.index(), error.path()),
+            (index, index_path(index).as_str())
+        );
+    }
+    for input in ["[]", " [ ] "] {
+        assert_eq!(stream_reader(input.as_bytes()).count(), 0);
+    }
+
+    // located the same as the whole export
+    let mut cards = fixtures();
+    cards[1]["text"][0]["cost"][0] = Value::from("PLASMA");
+    let input = serde_json::to_string_pretty(&cards).unwrap();
+    let expected = from_str(&input).unwrap_err();
+    let errors = stream_reader(input.as_bytes())
+        .filter_map(Result::err)
+        .collect::<Vec<_>>();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        (errors[0].index(), errors[0].card_id(), errors[0].path()),
+        (expected.index(), expected.card_id(), expected.path())
+    );
+    assert_eq!(errors[0].card_id(), Some("SVI_244"));
+    assert_eq!(errors[0].path(), "[1].text[0].cost[0]");
+    assert_eq!(stream_reader(input.as_bytes()).count(), 2);
+}
+
+#[test]
+fn exports() {
+    use crate::fixture;
+
+    for (file_name, json) in fixture::exports() {
+        let cards = from_str(&json).unwrap();
+
+        let partial = from_str_partial(&json).unwrap();
+        assert_eq!(partial.errors().len(), 0, "{file_name}");
+        assert_eq!(partial.cards(), cards, "{file_name}");
+
+        let streamed = stream_slice(json.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(streamed, cards, "{file_name}");
+
+        #[cfg(feature = "std")]
+        assert_eq!(
+            stream_reader(json.as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            cards,
+            "{file_name}"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! [JSON Schema] documents describing exactly what the [`Card`] model accepts.
+//!
+//! Enable the `schemars` feature to use this module.
+//!
+//! [JSON Schema]: https://json-schema.org
+
+use alloc::vec::Vec;
+
+use schemars::{
+    Schema, SchemaGenerator, generate::SchemaSettings, transform::transform_subschemas,
+};
+
+use crate::Card;
+
+/// The schema of a single export file, i.e. an array of cards.
+#[must_use]
+pub fn export() -> Schema {
+    generator().into_root_schema_for::<Vec<Card<'static>>>()
+}
+
+/// The schema of a single card.
+#[must_use]
+pub fn card() -> Schema {
+    generator().into_root_schema_for::<Card<'static>>()
+}
+
+fn generator() -> SchemaGenerator {
+    settings().into_generator()
+}
+
+pub(crate) fn settings() -> SchemaSettings {
+    SchemaSettings::draft2020_12().with_transform(deny_unevaluated_properties)
+}
+
+/// `deny_unknown_fields` on the variants of a nested internally tagged enum
+/// (i.e. [`Trainer`](crate::Trainer) within [`Card`]) can't be expressed with
+/// `additionalProperties`, since the outer tag is a sibling of the inner
+/// `oneOf`. `unevaluatedProperties` also sees the properties evaluated by the
+/// matching `oneOf` branch.
+fn deny_unevaluated_properties(schema: &mut Schema) {
+    if schema.get("properties").is_some() && schema.get("oneOf").is_some() {
+        schema.insert("unevaluatedProperties".into(), false.into());
+    }
+
+    transform_subschemas(&mut deny_unevaluated_properties, schema);
+}
+
+#[test]
+fn nested_tags_deny_unknown_fields() {
+    let schema = serde_json::to_value(card()).unwrap();
+
+    for variant in schema["oneOf"].as_array().unwrap() {
+        if variant.get("oneOf").is_some() {
+            assert_eq!(variant["unevaluatedProperties"], false);
+        } else {
+            assert_eq!(variant["additionalProperties"], false);
+        }
+    }
+
+    assert_eq!(
+        schema["$defs"]["Tcgl"]["properties"]["archetypeID"]["$ref"],
+        "#/$defs/U32Hex"
+    );
+    assert_eq!(schema["$defs"]["U32Hex"]["pattern"], "^0x[0-9a-fA-F]{8}$");
+}
@@ -3,18 +3,68 @@
 #![allow(clippy::enum_variant_names)]
 
 extern crate alloc;
+#[cfg(all(
+    any(
+        feature = "ffi",
+        feature = "parquet",
+        feature = "python",
+        feature = "std"
+    ),
+    not(test)
+))]
+extern crate std;
 
 use alloc::{borrow::Cow, vec::Vec};
 use core::num::{NonZeroU8, NonZeroU16};
 
 use serde::{Deserialize, Serialize};
-use time::PrimitiveDateTime;
+use time::UtcDateTime;
 use url::Url;
 
+use crate::id::{ArchetypeId, CardId, CardKey, LongFormId};
+
+#[cfg(test)]
+mod fixture;
+mod owned;
 /// Inlined version of <https://docs.rs/time/latest/time/serde/macro.format_description.html> to allow for this crate to be `#![no_std]`.
 mod reldate;
+mod serde_name;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod assets;
+pub mod binary;
+pub mod calendar;
+pub mod collector_number;
+pub mod completion;
+pub mod composite;
+pub mod deck;
+pub mod dimensions;
+pub mod fallback;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod id;
+pub mod index;
+pub mod inventory;
+#[cfg(feature = "json")]
+pub mod json;
+#[cfg(feature = "schemars")]
+pub mod json_schema;
+pub mod localize;
+pub mod market;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod rules;
+pub mod set;
+pub mod table;
+#[cfg(feature = "typescript")]
+pub mod typescript;
+pub mod validate;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(
     deny_unknown_fields,
     tag = "card_type",
@@ -26,7 +76,149 @@ pub enum Card<'a> {
     Energy(#[serde(borrow)] Energy<'a>),
 }
 
+macro_rules! each_card {
+    ($card:expr, $c:ident => $body:expr) => {
+        match $card {
+            Card::Pokemon($c) => $body,
+            Card::Trainer(Trainer::Item($c)) => $body,
+            Card::Trainer(Trainer::Supporter($c)) => $body,
+            Card::Trainer(Trainer::Tool($c)) => $body,
+            Card::Trainer(Trainer::Stadium($c)) => $body,
+            Card::Energy(Energy::Basic($c)) => $body,
+            Card::Energy(Energy::Special($c)) => $body,
+        }
+    };
+}
+
+impl<'a> Card<'a> {
+    #[must_use]
+    pub fn name(&self) -> &str {
+        each_card!(self, c => &c.name)
+    }
+
+    #[must_use]
+    pub fn lang(&self) -> &Lang {
+        each_card!(self, c => &c.lang)
+    }
+
+    #[must_use]
+    pub fn foil(&self) -> Option<&Foil> {
+        each_card!(self, c => c.foil.as_ref())
+    }
+
+    #[must_use]
+    pub fn size(&self) -> &CardSize {
+        each_card!(self, c => &c.size)
+    }
+
+    #[must_use]
+    pub fn back(&self) -> &CardBack {
+        each_card!(self, c => &c.back)
+    }
+
+    #[must_use]
+    pub fn regulation_mark(&self) -> Option<&RegulationMark> {
+        match self {
+            Card::Pokemon(c) => c.regulation_mark.as_ref(),
+            Card::Trainer(Trainer::Item(c)) => c.regulation_mark.as_ref(),
+            Card::Trainer(Trainer::Supporter(c)) => c.regulation_mark.as_ref(),
+            Card::Trainer(Trainer::Tool(c)) => c.regulation_mark.as_ref(),
+            Card::Trainer(Trainer::Stadium(c)) => c.regulation_mark.as_ref(),
+            Card::Energy(Energy::Basic(_)) => None,
+            Card::Energy(Energy::Special(c)) => c.regulation_mark.as_ref(),
+        }
+    }
+
+    #[must_use]
+    pub fn set_icon(&self) -> &str {
+        each_card!(self, c => &c.set_icon)
+    }
+
+    #[must_use]
+    pub fn collector_number(&self) -> &CollectorNumber<'a> {
+        each_card!(self, c => &c.collector_number)
+    }
+
+    #[must_use]
+    pub fn rarity(&self) -> Option<&Rarity> {
+        each_card!(self, c => c.rarity.as_ref())
+    }
+
+    #[must_use]
+    pub fn copyright(&self) -> Option<&Copyright<'a>> {
+        each_card!(self, c => c.copyright.as_ref())
+    }
+
+    #[must_use]
+    pub fn tags(&self) -> &[CardTag] {
+        each_card!(self, c => c.tags.as_deref().unwrap_or_default())
+    }
+
+    #[must_use]
+    pub fn hp(&self) -> Option<NonZeroU16> {
+        match self {
+            Card::Pokemon(c) => Some(c.hp),
+            Card::Trainer(Trainer::Item(c)) => c.hp,
+            Card::Trainer(_) | Card::Energy(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub fn types(&self) -> &[EnergyType] {
+        match self {
+            Card::Pokemon(c) => &c.types,
+            Card::Energy(Energy::Basic(c)) => &c.types,
+            Card::Trainer(_) | Card::Energy(Energy::Special(_)) => &[],
+        }
+    }
+
+    #[must_use]
+    pub fn text(&self) -> &[Text<'a>] {
+        match self {
+            Card::Pokemon(c) => &c.text,
+            Card::Trainer(Trainer::Item(c)) => &c.text,
+            Card::Trainer(Trainer::Supporter(c)) => &c.text,
+            Card::Trainer(Trainer::Tool(c)) => &c.text,
+            Card::Trainer(Trainer::Stadium(c)) => &c.text,
+            Card::Energy(Energy::Basic(_)) => &[],
+            Card::Energy(Energy::Special(c)) => &c.text,
+        }
+    }
+
+    pub fn attacks(&self) -> impl Iterator<Item = &Attack<'a>> {
+        self.text().iter().filter_map(|text| match text {
+            Text::Attack(attack) => Some(attack),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    pub fn tcgl(&self) -> &Tcgl<'a> {
+        each_card!(self, c => &c.ext.tcgl)
+    }
+
+    #[must_use]
+    pub fn images(&self) -> &Images {
+        each_card!(self, c => &c.images)
+    }
+
+    /// The serialized `card_type` and `subtype` tags of this card.
+    #[must_use]
+    pub fn type_tags(&self) -> (&'static str, Option<&'static str>) {
+        match self {
+            Card::Pokemon(_) => ("POKEMON", None),
+            Card::Trainer(Trainer::Item(_)) => ("TRAINER", Some("ITEM")),
+            Card::Trainer(Trainer::Supporter(_)) => ("TRAINER", Some("SUPPORTER")),
+            Card::Trainer(Trainer::Tool(_)) => ("TRAINER", Some("TOOL")),
+            Card::Trainer(Trainer::Stadium(_)) => ("TRAINER", Some("STADIUM")),
+            Card::Energy(Energy::Basic(_)) => ("ENERGY", Some("BASIC")),
+            Card::Energy(Energy::Special(_)) => ("ENERGY", Some("SPECIAL")),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Pokemon<'a> {
     #[serde(borrow)]
@@ -40,7 +232,8 @@ pub struct Pokemon<'a> {
     back: CardBack,
     #[serde(borrow, skip_serializing_if = "Option::is_none")]
     artists: Option<Artists<'a>>,
-    regulation_mark: RegulationMark,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    regulation_mark: Option<RegulationMark>,
     #[serde(borrow)]
     set_icon: Cow<'a, str>,
     #[serde(borrow)]
@@ -72,6 +265,7 @@ pub struct Pokemon<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(
     deny_unknown_fields,
     tag = "subtype",
@@ -85,6 +279,7 @@ pub enum Trainer<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Item<'a> {
     #[serde(borrow)]
@@ -96,7 +291,8 @@ pub struct Item<'a> {
     back: CardBack,
     #[serde(borrow, skip_serializing_if = "Option::is_none")]
     artists: Option<Artists<'a>>,
-    regulation_mark: RegulationMark,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    regulation_mark: Option<RegulationMark>,
     #[serde(borrow)]
     set_icon: Cow<'a, str>,
     #[serde(borrow)]
@@ -121,6 +317,7 @@ pub struct Item<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Supporter<'a> {
     #[serde(borrow)]
@@ -134,7 +331,8 @@ pub struct Supporter<'a> {
     back: CardBack,
     #[serde(borrow, skip_serializing_if = "Option::is_none")]
     artists: Option<Artists<'a>>,
-    regulation_mark: RegulationMark,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    regulation_mark: Option<RegulationMark>,
     #[serde(borrow)]
     set_icon: Cow<'a, str>,
     #[serde(borrow)]
@@ -153,6 +351,7 @@ pub struct Supporter<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Tool<'a> {
     #[serde(borrow)]
@@ -164,7 +363,8 @@ pub struct Tool<'a> {
     back: CardBack,
     #[serde(borrow, skip_serializing_if = "Option::is_none")]
     artists: Option<Artists<'a>>,
-    regulation_mark: RegulationMark,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    regulation_mark: Option<RegulationMark>,
     #[serde(borrow)]
     set_icon: Cow<'a, str>,
     #[serde(borrow)]
@@ -185,6 +385,7 @@ pub struct Tool<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Stadium<'a> {
     #[serde(borrow)]
@@ -196,7 +397,8 @@ pub struct Stadium<'a> {
     back: CardBack,
     #[serde(borrow, skip_serializing_if = "Option::is_none")]
     artists: Option<Artists<'a>>,
-    regulation_mark: RegulationMark,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    regulation_mark: Option<RegulationMark>,
     #[serde(borrow)]
     set_icon: Cow<'a, str>,
     #[serde(borrow)]
@@ -217,6 +419,7 @@ pub struct Stadium<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(
     deny_unknown_fields,
     tag = "subtype",
@@ -228,6 +431,7 @@ pub enum Energy<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct BasicEnergy<'a> {
     #[serde(borrow)]
@@ -254,6 +458,7 @@ pub struct BasicEnergy<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SpecialEnergy<'a> {
     #[serde(borrow)]
@@ -263,7 +468,8 @@ pub struct SpecialEnergy<'a> {
     foil: Option<Foil>,
     size: CardSize,
     back: CardBack,
-    regulation_mark: RegulationMark,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    regulation_mark: Option<RegulationMark>,
     #[serde(borrow)]
     set_icon: Cow<'a, str>,
     #[serde(borrow)]
@@ -283,7 +489,23 @@ pub struct SpecialEnergy<'a> {
     images: Images,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(
+        eq,
+        eq_int,
+        frozen,
+        hash,
+        module = "malie",
+        rename_all = "SCREAMING_SNAKE_CASE"
+    ),
+    allow(
+        clippy::unsafe_derive_deserialize,
+        reason = "pyclass generated methods"
+    )
+)]
 #[serde(deny_unknown_fields)]
 pub enum Lang {
     #[serde(rename = "en-US")]
@@ -300,9 +522,18 @@ pub enum Lang {
     Es419,
     #[serde(rename = "pt-BR")]
     PtBr,
+    #[serde(rename = "ja-JP")]
+    JaJp,
+    #[serde(rename = "ko-KR")]
+    KoKr,
+    #[serde(rename = "zh-TW")]
+    ZhTw,
+    #[serde(rename = "zh-CN")]
+    ZhCn,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Foil {
     #[serde(rename = "type")]
@@ -310,7 +541,8 @@ pub struct Foil {
     mask: FoilMask,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields, rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum FoilType {
     Stamped,
@@ -327,7 +559,8 @@ pub enum FoilType {
     Tinsel,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields, rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum FoilMask {
     Stamped,
@@ -339,20 +572,29 @@ pub enum FoilMask {
     ReverseLaminate,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields, rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CardSize {
     Standard,
+    /// Oversized copies of promos, sold in collection boxes.
+    Jumbo,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum CardBack {
     #[serde(rename = "POKEMON_1999")]
     Pokemon1999,
+    /// The Japanese "Pocket Monsters" back, used until the international back
+    /// replaced it in 2002.
+    #[serde(rename = "POCKET_MONSTERS_1996")]
+    PocketMonsters1996,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Artists<'a> {
     #[serde(borrow)]
@@ -361,7 +603,8 @@ pub struct Artists<'a> {
     list: Vec<Cow<'a, str>>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum RegulationMark {
     A,
@@ -376,6 +619,7 @@ pub enum RegulationMark {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct CollectorNumber<'a> {
     #[serde(borrow)]
@@ -388,13 +632,30 @@ pub struct CollectorNumber<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Rarity {
     designation: RarityDesignation,
     icon: RarityIcon,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(
+        eq,
+        eq_int,
+        frozen,
+        hash,
+        module = "malie",
+        rename_all = "SCREAMING_SNAKE_CASE"
+    ),
+    allow(
+        clippy::unsafe_derive_deserialize,
+        reason = "pyclass generated methods"
+    )
+)]
 #[serde(deny_unknown_fields, rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RarityDesignation {
     Common,
@@ -411,9 +672,24 @@ pub enum RarityDesignation {
     AceSpecRare,
     MegaHyperRare,
     BlackWhiteRare,
+    HoloRare,
+    HoloExRare,
+    HoloGxRare,
+    HoloVRare,
+    HoloVmaxRare,
+    HoloVstarRare,
+    BreakRare,
+    PrismStarRare,
+    SecretRare,
+    RainbowRare,
+    ShinyGxRare,
+    AmazingRare,
+    RadiantRare,
+    TrainerGalleryHoloRare,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields, rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum RarityIcon {
     SolidCircle,
@@ -433,6 +709,7 @@ pub enum RarityIcon {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Copyright<'a> {
     #[serde(borrow)]
@@ -442,6 +719,7 @@ pub struct Copyright<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields, rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CardTag {
     ExLower,
@@ -455,17 +733,38 @@ pub enum CardTag {
     AceSpec,
     TrainersPokemon,
     MegaEvolution,
+    ExUpper,
+    Break,
+    Gx,
+    TagTeam,
+    PrismStar,
+    UltraBeast,
+    V,
+    Vmax,
+    Vstar,
+    VUnion,
+    Radiant,
+    SingleStrike,
+    RapidStrike,
+    FusionStrike,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields, rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Stage {
     Basic,
     Stage1,
     Stage2,
+    Vmax,
+    Vstar,
+    VUnion,
+    Break,
+    Restored,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Weakness {
     amount: NonZeroU8,
@@ -474,6 +773,7 @@ pub struct Weakness {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum WeaknessOperator {
     #[serde(rename = "+")]
@@ -483,6 +783,7 @@ pub enum WeaknessOperator {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Resistance {
     amount: u8,
@@ -491,6 +792,7 @@ pub struct Resistance {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum ResistanceOperator {
     #[serde(rename = "-")]
@@ -498,6 +800,7 @@ pub enum ResistanceOperator {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Ext<'a> {
     #[serde(borrow)]
@@ -505,27 +808,35 @@ pub struct Ext<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Tcgl<'a> {
-    #[serde(rename = "archetypeID", with = "crate::u32_hex")]
-    archetype_id: u32,
+    #[serde(rename = "archetypeID")]
+    #[cfg_attr(feature = "schemars", schemars(with = "crate::u32_hex::U32Hex"))]
+    archetype_id: ArchetypeId,
     #[serde(borrow, rename = "cardID")]
-    card_id: Cow<'a, str>,
+    #[cfg_attr(feature = "schemars", schemars(with = "str"))]
+    card_id: CardId<'a>,
     #[serde(borrow)]
-    key: Cow<'a, str>,
+    #[cfg_attr(feature = "schemars", schemars(with = "str"))]
+    key: CardKey<'a>,
     #[serde(borrow, rename = "longFormID")]
-    long_form_id: Cow<'a, str>,
+    #[cfg_attr(feature = "schemars", schemars(with = "str"))]
+    long_form_id: LongFormId<'a>,
     #[serde(with = "reldate")]
-    reldate: PrimitiveDateTime,
+    #[cfg_attr(feature = "schemars", schemars(with = "reldate::Reldate"))]
+    reldate: UtcDateTime,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Images {
     tcgl: TcglImages,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct TcglImages {
     jpg: ImageJpg,
@@ -534,12 +845,14 @@ pub struct TcglImages {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ImageJpg {
     front: Url,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ImagePng {
     front: Url,
@@ -550,6 +863,7 @@ pub struct ImagePng {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ImageTex {
     front: Url,
@@ -560,6 +874,7 @@ pub struct ImageTex {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Damage {
     // REVIEW: Multiple of 10, nonzero?
@@ -569,6 +884,7 @@ pub struct Damage {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum DamageSuffix {
     #[serde(rename = "+")]
@@ -580,6 +896,7 @@ pub enum DamageSuffix {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields, tag = "kind", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Text<'a> {
     Attack(#[serde(borrow)] Attack<'a>),
@@ -591,6 +908,7 @@ pub enum Text<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Attack<'a> {
     #[serde(borrow)]
@@ -603,6 +921,7 @@ pub struct Attack<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Ability<'a> {
     #[serde(borrow)]
@@ -612,6 +931,7 @@ pub struct Ability<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct RuleBox<'a> {
     #[serde(borrow)]
@@ -621,6 +941,7 @@ pub struct RuleBox<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Effect<'a> {
     #[serde(borrow)]
@@ -630,6 +951,7 @@ pub struct Effect<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct TextBox<'a> {
     #[serde(borrow, skip_serializing_if = "Option::is_none")]
@@ -639,13 +961,30 @@ pub struct TextBox<'a> {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Reminder<'a> {
     #[serde(borrow)]
     text: Cow<'a, str>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(
+        eq,
+        eq_int,
+        frozen,
+        hash,
+        module = "malie",
+        rename_all = "SCREAMING_SNAKE_CASE"
+    ),
+    allow(
+        clippy::unsafe_derive_deserialize,
+        reason = "pyclass generated methods"
+    )
+)]
 #[serde(deny_unknown_fields, rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum EnergyType {
     Grass,
@@ -658,9 +997,13 @@ pub enum EnergyType {
     Metal,
     Dragon,
     Colorless,
+    Fairy,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+// Deserialized by hand, so that errors list every expected value rather than
+// only failing to match the untagged variant.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields, rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AttackCost {
     Free,
@@ -668,12 +1011,83 @@ pub enum AttackCost {
     Energy(EnergyType),
 }
 
+/// Every value of an [`AttackCost`], `FREE` followed by each [`EnergyType`].
+const ATTACK_COSTS: &[&str] = &[
+    "FREE",
+    "GRASS",
+    "FIRE",
+    "WATER",
+    "LIGHTNING",
+    "PSYCHIC",
+    "FIGHTING",
+    "DARKNESS",
+    "METAL",
+    "DRAGON",
+    "COLORLESS",
+    "FAIRY",
+];
+
+impl<'de> Deserialize<'de> for AttackCost {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{self, IntoDeserializer};
+
+        struct Visitor;
+
+        impl de::Visitor<'_> for Visitor {
+            type Value = AttackCost;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("an attack cost")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<AttackCost, E> {
+                if value == "FREE" {
+                    return Ok(AttackCost::Free);
+                }
+
+                EnergyType::deserialize(IntoDeserializer::<de::value::Error>::into_deserializer(
+                    value,
+                ))
+                .map(AttackCost::Energy)
+                .map_err(|_| E::unknown_variant(value, ATTACK_COSTS))
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
 pub(crate) mod u32_hex {
     use alloc::string::String;
 
     use serde::{Deserialize, de};
 
-    const HEX_ENCODING_PREFIX: &str = "0x";
+    pub(crate) const HEX_ENCODING_PREFIX: &str = "0x";
+    const EXPECTED: &str = "hex encoded u32 bytes, 0x-prefixed";
+
+    #[cfg(feature = "schemars")]
+    pub(crate) struct U32Hex;
+
+    #[cfg(feature = "schemars")]
+    impl schemars::JsonSchema for U32Hex {
+        fn schema_name() -> alloc::borrow::Cow<'static, str> {
+            "U32Hex".into()
+        }
+
+        fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+            schemars::json_schema!({
+                "type": "string",
+                "description": "hex encoded u32 bytes, 0x-prefixed",
+                "pattern": "^0x[0-9a-fA-F]{8}$",
+            })
+        }
+    }
+
+    /// Parse hex encoded big endian `u32` bytes, `0x`-prefixed.
+    pub(crate) fn parse(s: &str) -> Option<u32> {
+        let bytes = hex::decode(s.strip_prefix(HEX_ENCODING_PREFIX)?).ok()?;
+        bytes.try_into().ok().map(u32::from_be_bytes)
+    }
 
     #[expect(clippy::trivially_copy_pass_by_ref, reason = "required by serde api")]
     pub(crate) fn serialize<S>(data: &u32, serializer: S) -> Result<S::Ok, S::Error>
@@ -691,30 +1105,7 @@ pub(crate) mod u32_hex {
         D: serde::Deserializer<'de>,
     {
         <String>::deserialize(deserializer).and_then(|s| {
-            s.strip_prefix(HEX_ENCODING_PREFIX)
-                .ok_or(de::Error::invalid_value(
-                    de::Unexpected::Str(&s),
-                    &"hex encoded u32 bytes, 0x-prefixed",
-                ))
-                .and_then(|maybe_hex| {
-                    hex::decode(maybe_hex)
-                        .map_err(|_| {
-                            de::Error::invalid_value(
-                                de::Unexpected::Str(maybe_hex),
-                                &"hex encoded u32 bytes, 0x-prefixed",
-                            )
-                        })
-                        .and_then(|hex| {
-                            hex.try_into()
-                                .map_err(|_| {
-                                    de::Error::invalid_value(
-                                        de::Unexpected::Str(maybe_hex),
-                                        &"hex encoded u32 bytes, 0x-prefixed",
-                                    )
-                                })
-                                .map(u32::from_be_bytes)
-                        })
-                })
+            parse(&s).ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(&s), &EXPECTED))
         })
     }
 }
@@ -747,6 +1138,34 @@ fn serde() {
             serde_json::from_str::<serde_json::Value>(&roundtrip).unwrap()
         );
 
+        let encoded = binary::to_vec(&cards).unwrap();
+
+        assert_eq!(binary::from_slice::<Vec<Card>>(&encoded).unwrap(), cards);
+
         // dbg!(&cards);
     }
 }
+
+#[test]
+fn attack_costs() {
+    use alloc::{format, vec::Vec};
+
+    // serde lists every variant of `EnergyType` in its own error
+    let error = serde_json::from_str::<EnergyType>(r#""PLASMA""#)
+        .unwrap_err()
+        .to_string();
+    let energy_types = ATTACK_COSTS[1..]
+        .iter()
+        .map(|name| format!("`{name}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    assert!(
+        error.contains(&format!("expected one of {energy_types} ")),
+        "{error}"
+    );
+
+    for &name in ATTACK_COSTS {
+        let cost = serde_json::from_value::<AttackCost>(name.into()).unwrap();
+        assert_eq!(serde_json::to_value(&cost).unwrap(), name);
+    }
+}
@@ -0,0 +1,253 @@
+//! Display names for the enums of the model, in every [`Lang`].
+//!
+//! Names printed on cards, i.e. types, stages and tags, follow the printed
+//! text of each language. Rarities and foils are never printed in full, so
+//! they are given descriptive names, except for Japanese, Korean and Chinese
+//! rarities, which use the rarity code printed on those cards, i.e. `SAR`.
+//!
+//! ```
+//! use malie::Lang;
+//!
+//! # let stage = serde_json::from_str::<malie::Stage>(r#""STAGE1""#).unwrap();
+//! assert_eq!(stage.localized(&Lang::EnUs), "Stage 1");
+//! assert_eq!(stage.localized(&Lang::FrFr), "Niveau 1");
+//! assert_eq!(stage.localized(&Lang::DeDe), "Phase 1");
+//! ```
+
+use crate::{CardTag, EnergyType, FoilMask, FoilType, Lang, RarityDesignation, Stage, Trainer};
+
+/// The index of `lang` into each row of names, which list the languages in
+/// the order they are declared in [`Lang`].
+fn index(lang: &Lang) -> usize {
+    match lang {
+        Lang::EnUs => 0,
+        Lang::FrFr => 1,
+        Lang::ItIt => 2,
+        Lang::DeDe => 3,
+        Lang::EsEs => 4,
+        Lang::Es419 => 5,
+        Lang::PtBr => 6,
+        Lang::JaJp => 7,
+        Lang::KoKr => 8,
+        Lang::ZhTw => 9,
+        Lang::ZhCn => 10,
+    }
+}
+
+macro_rules! localized {
+    ($($ty:ident { $($variant:ident => [$($name:literal),+ $(,)?],)+ })+) => {$(
+        impl $ty {
+            /// The name of the variant as displayed in `lang`.
+            #[must_use]
+            pub fn localized(&self, lang: &Lang) -> &'static str {
+                let names: [&'static str; 11] = match self {
+                    $($ty::$variant => [$($name),+],)+
+                };
+                names[index(lang)]
+            }
+        }
+    )+};
+}
+
+impl Trainer<'_> {
+    /// The name of the trainer subtype as displayed in `lang`.
+    #[must_use]
+    pub fn localized(&self, lang: &Lang) -> &'static str {
+        let names = match self {
+            Trainer::Item(_) => [
+                "Item",
+                "Objet",
+                "Strumento",
+                "Item",
+                "Objeto",
+                "Objeto",
+                "Item",
+                "グッズ",
+                "굿즈",
+                "物品",
+                "物品",
+            ],
+            Trainer::Supporter(_) => [
+                "Supporter",
+                "Supporter",
+                "Aiuto",
+                "Unterstützer",
+                "Partidario",
+                "Partidario",
+                "Apoiador",
+                "サポート",
+                "서포트",
+                "支援者",
+                "支援者",
+            ],
+            Trainer::Tool(_) => [
+                "Pokémon Tool",
+                "Outil Pokémon",
+                "Oggetto Pokémon",
+                "Pokémon-Ausrüstung",
+                "Herramienta Pokémon",
+                "Herramienta Pokémon",
+                "Ferramenta Pokémon",
+                "ポケモンのどうぐ",
+                "포켓몬의 도구",
+                "寶可夢道具",
+                "宝可梦道具",
+            ],
+            Trainer::Stadium(_) => [
+                "Stadium",
+                "Stade",
+                "Stadio",
+                "Stadion",
+                "Estadio",
+                "Estadio",
+                "Estádio",
+                "スタジアム",
+                "스타디움",
+                "競技場",
+                "竞技场",
+            ],
+        };
+        names[index(lang)]
+    }
+}
+
+localized! {
+    EnergyType {
+        Grass => ["Grass", "Plante", "Erba", "Pflanze", "Planta", "Planta", "Planta", "草", "풀", "草", "草"],
+        Fire => ["Fire", "Feu", "Fuoco", "Feuer", "Fuego", "Fuego", "Fogo", "炎", "불꽃", "火", "火"],
+        Water => ["Water", "Eau", "Acqua", "Wasser", "Agua", "Agua", "Água", "水", "물", "水", "水"],
+        Lightning => ["Lightning", "Électrique", "Lampo", "Elektro", "Rayo", "Rayo", "Elétrico", "雷", "번개", "雷", "雷"],
+        Psychic => ["Psychic", "Psy", "Psico", "Psycho", "Psíquico", "Psíquico", "Psíquico", "超", "초", "超", "超"],
+        Fighting => ["Fighting", "Combat", "Lotta", "Kampf", "Lucha", "Lucha", "Luta", "闘", "격투", "鬥", "斗"],
+        Darkness => ["Darkness", "Obscurité", "Oscurità", "Finsternis", "Oscuro", "Oscuro", "Escuridão", "悪", "악", "惡", "恶"],
+        Metal => ["Metal", "Métal", "Metallo", "Metall", "Metálico", "Metálico", "Metal", "鋼", "강철", "鋼", "钢"],
+        Dragon => ["Dragon", "Dragon", "Drago", "Drache", "Dragón", "Dragón", "Dragão", "ドラゴン", "드래곤", "龍", "龙"],
+        Colorless => ["Colorless", "Incolore", "Incolore", "Farblos", "Incoloro", "Incoloro", "Incolor", "無色", "무색", "無色", "无色"],
+        Fairy => ["Fairy", "Fée", "Folletto", "Fee", "Hada", "Hada", "Fada", "フェアリー", "페어리", "妖精", "妖精"],
+    }
+
+    Stage {
+        Basic => ["Basic", "De base", "Base", "Basis", "Básico", "Básico", "Básico", "たね", "기본", "基礎", "基础"],
+        Stage1 => ["Stage 1", "Niveau 1", "Fase 1", "Phase 1", "Fase 1", "Fase 1", "Estágio 1", "1進化", "1진화", "1階進化", "1阶进化"],
+        Stage2 => ["Stage 2", "Niveau 2", "Fase 2", "Phase 2", "Fase 2", "Fase 2", "Estágio 2", "2進化", "2진화", "2階進化", "2阶进化"],
+        Vmax => ["VMAX", "VMAX", "VMAX", "VMAX", "VMAX", "VMAX", "VMAX", "VMAX", "VMAX", "VMAX", "VMAX"],
+        Vstar => ["VSTAR", "VSTAR", "V ASTRO", "VSTAR", "V-ASTRO", "V-ASTRO", "V-ASTRO", "VSTAR", "VSTAR", "VSTAR", "VSTAR"],
+        VUnion => ["V-UNION", "V-UNION", "V-UNIONE", "V-UNION", "V-UNIÓN", "V-UNIÓN", "V-UNIÃO", "V-UNION", "V-UNION", "V-UNION", "V-UNION"],
+        Break => ["BREAK", "TURBO", "TURBO", "TURBO", "TURBO", "TURBO", "TURBO", "BREAK", "BREAK", "BREAK", "BREAK"],
+        Restored => ["Restored", "Restauré", "Rigenerato", "Wiederbelebt", "Restaurado", "Restaurado", "Restaurado", "復元", "복원", "復原", "复原"],
+    }
+
+    CardTag {
+        ExLower => ["ex", "ex", "ex", "ex", "ex", "ex", "ex", "ex", "ex", "ex", "ex"],
+        Tera => ["Tera", "Téracristal", "Teracristal", "Terakristall", "Teracristal", "Teracristal", "Terastal", "テラスタル", "테라스탈", "太晶", "太晶"],
+        Item => ["Item", "Objet", "Strumento", "Item", "Objeto", "Objeto", "Item", "グッズ", "굿즈", "物品", "物品"],
+        Tool => ["Pokémon Tool", "Outil Pokémon", "Oggetto Pokémon", "Pokémon-Ausrüstung", "Herramienta Pokémon", "Herramienta Pokémon", "Ferramenta Pokémon", "ポケモンのどうぐ", "포켓몬의 도구", "寶可夢道具", "宝可梦道具"],
+        PlayableTrainer => ["Playable Trainer", "Dresseur jouable", "Allenatore giocabile", "Spielbarer Trainer", "Entrenador jugable", "Entrenador jugable", "Treinador jogável", "ポケモンとして使えるトレーナーズ", "포켓몬으로 사용하는 트레이너스", "可當作寶可夢的訓練家", "可当作宝可梦的训练家"],
+        Future => ["Future", "Futur", "Futuro", "Futur", "Futuro", "Futuro", "Futuro", "ミライ", "미래", "未來", "未来"],
+        Ancient => ["Ancient", "Passé", "Antico", "Antik", "Antiguo", "Antiguo", "Ancestral", "コダイ", "고대", "古代", "古代"],
+        Shiny => ["Shiny", "Chromatique", "Cromatico", "Schillernd", "Variocolor", "Variocolor", "Brilhante", "色違い", "색이 다른", "異色", "异色"],
+        AceSpec => ["ACE SPEC", "HIGH-TECH", "ASSO TATTICO", "ASS-KLASSE", "AS TÁCTICO", "AS TÁCTICO", "ÁS TÁTICO", "ACE SPEC", "ACE SPEC", "ACE SPEC", "ACE SPEC"],
+        TrainersPokemon => ["Trainer's Pokémon", "Pokémon de Dresseur", "Pokémon dell'Allenatore", "Trainer-Pokémon", "Pokémon de Entrenador", "Pokémon de Entrenador", "Pokémon de Treinador", "トレーナーのポケモン", "트레이너의 포켓몬", "訓練家的寶可夢", "训练家的宝可梦"],
+        MegaEvolution => ["Mega Evolution", "Méga-Évolution", "Megaevoluzione", "Mega-Entwicklung", "Megaevolución", "Megaevolución", "Megaevolução", "メガシンカ", "메가진화", "超級進化", "超级进化"],
+        ExUpper => ["EX", "EX", "EX", "EX", "EX", "EX", "EX", "EX", "EX", "EX", "EX"],
+        Break => ["BREAK", "TURBO", "TURBO", "TURBO", "TURBO", "TURBO", "TURBO", "BREAK", "BREAK", "BREAK", "BREAK"],
+        Gx => ["GX", "GX", "GX", "GX", "GX", "GX", "GX", "GX", "GX", "GX", "GX"],
+        TagTeam => ["TAG TEAM", "TAG TEAM", "TAG TEAM", "TAG TEAM", "TAG TEAM", "TAG TEAM", "TAG TEAM", "TAG TEAM", "TAG TEAM", "TAG TEAM", "TAG TEAM"],
+        PrismStar => ["Prism Star", "Prisme Étoile", "Prisma Stella", "Prisma-Stern", "Prisma Estrella", "Prisma Estrella", "Prisma Estrela", "プリズムスター", "프리즘스타", "稜鏡之星", "棱镜之星"],
+        UltraBeast => ["Ultra Beast", "Ultra-Chimère", "Ultracreatura", "Ultrabestie", "Ultraente", "Ultraente", "Ultracriatura", "ウルトラビースト", "울트라비스트", "究極異獸", "究极异兽"],
+        V => ["V", "V", "V", "V", "V", "V", "V", "V", "V", "V", "V"],
+        Vmax => ["VMAX", "VMAX", "VMAX", "VMAX", "VMAX", "VMAX", "VMAX", "VMAX", "VMAX", "VMAX", "VMAX"],
+        Vstar => ["VSTAR", "VSTAR", "V ASTRO", "VSTAR", "V-ASTRO", "V-ASTRO", "V-ASTRO", "VSTAR", "VSTAR", "VSTAR", "VSTAR"],
+        VUnion => ["V-UNION", "V-UNION", "V-UNIONE", "V-UNION", "V-UNIÓN", "V-UNIÓN", "V-UNIÃO", "V-UNION", "V-UNION", "V-UNION", "V-UNION"],
+        Radiant => ["Radiant", "Radieux", "Radiante", "Strahlend", "Radiante", "Radiante", "Radiante", "かがやく", "찬란한", "光輝", "光辉"],
+        SingleStrike => ["Single Strike", "Poing Final", "Singolcolpo", "Fokussierter Stil", "Golpe Brusco", "Golpe Brusco", "Golpe Decisivo", "一撃", "일격", "一擊", "一击"],
+        RapidStrike => ["Rapid Strike", "Mille Poings", "Pluricolpo", "Fließender Stil", "Golpe Fluido", "Golpe Fluido", "Golpe Fluido", "連撃", "연격", "連擊", "连击"],
+        FusionStrike => ["Fusion Strike", "Fusion", "Fusione", "Fusionsstil", "Fusión", "Fusión", "Fusão", "フュージョン", "퓨전", "融合", "融合"],
+    }
+
+    RarityDesignation {
+        Common => ["Common", "Commune", "Comune", "Häufig", "Común", "Común", "Comum", "C", "C", "C", "C"],
+        Uncommon => ["Uncommon", "Peu commune", "Non comune", "Nicht so häufig", "Poco común", "Poco común", "Incomum", "U", "U", "U", "U"],
+        Rare => ["Rare", "Rare", "Rara", "Selten", "Rara", "Rara", "Rara", "R", "R", "R", "R"],
+        DoubleRare => ["Double Rare", "Double rare", "Doppia rara", "Doppelselten", "Rara doble", "Rara doble", "Rara dupla", "RR", "RR", "RR", "RR"],
+        IllustrationRare => ["Illustration Rare", "Illustration rare", "Rara illustrazione", "Illustrationsselten", "Rara ilustración", "Rara ilustración", "Rara ilustração", "AR", "AR", "AR", "AR"],
+        SpecialIllustrationRare => ["Special Illustration Rare", "Illustration spéciale rare", "Rara illustrazione speciale", "Spezielle Illustrationsselten", "Rara ilustración especial", "Rara ilustración especial", "Rara ilustração especial", "SAR", "SAR", "SAR", "SAR"],
+        UltraRare => ["Ultra Rare", "Ultra rare", "Ultra rara", "Ultraselten", "Ultra rara", "Ultra rara", "Ultra rara", "SR", "SR", "SR", "SR"],
+        HyperRare => ["Hyper Rare", "Hyper rare", "Iper rara", "Hyperselten", "Hiper rara", "Hiper rara", "Hiper rara", "UR", "UR", "UR", "UR"],
+        ShinyRare => ["Shiny Rare", "Chromatique rare", "Rara cromatica", "Schillernd selten", "Rara variocolor", "Rara variocolor", "Rara brilhante", "S", "S", "S", "S"],
+        ShinyUltraRare => ["Shiny Ultra Rare", "Chromatique ultra rare", "Ultra rara cromatica", "Schillernd ultraselten", "Ultra rara variocolor", "Ultra rara variocolor", "Ultra rara brilhante", "SSR", "SSR", "SSR", "SSR"],
+        Promo => ["Promo", "Promo", "Promo", "Promo", "Promocional", "Promocional", "Promocional", "PROMO", "PROMO", "PROMO", "PROMO"],
+        AceSpecRare => ["ACE SPEC Rare", "HIGH-TECH rare", "Rara ASSO TATTICO", "ASS-KLASSE selten", "Rara AS TÁCTICO", "Rara AS TÁCTICO", "Rara ÁS TÁTICO", "ACE", "ACE", "ACE", "ACE"],
+        MegaHyperRare => ["Mega Hyper Rare", "Méga hyper rare", "Mega iper rara", "Mega-hyperselten", "Mega hiper rara", "Mega hiper rara", "Mega hiper rara", "MUR", "MUR", "MUR", "MUR"],
+        BlackWhiteRare => ["Black White Rare", "Noir blanc rare", "Rara bianco nero", "Schwarz-Weiß-selten", "Rara negro blanco", "Rara negro blanco", "Rara preto branco", "BWR", "BWR", "BWR", "BWR"],
+        HoloRare => ["Holo Rare", "Holo rare", "Rara holo", "Holo selten", "Rara holo", "Rara holo", "Rara holo", "R", "R", "R", "R"],
+        HoloExRare => ["Holo EX Rare", "Rare holo EX", "Rara holo EX", "Selten holo EX", "Rara holo EX", "Rara holo EX", "Rara holo EX", "RR", "RR", "RR", "RR"],
+        HoloGxRare => ["Holo GX Rare", "Rare holo GX", "Rara holo GX", "Selten holo GX", "Rara holo GX", "Rara holo GX", "Rara holo GX", "RR", "RR", "RR", "RR"],
+        HoloVRare => ["Holo V Rare", "Rare holo V", "Rara holo V", "Selten holo V", "Rara holo V", "Rara holo V", "Rara holo V", "RR", "RR", "RR", "RR"],
+        HoloVmaxRare => ["Holo VMAX Rare", "Rare holo VMAX", "Rara holo VMAX", "Selten holo VMAX", "Rara holo VMAX", "Rara holo VMAX", "Rara holo VMAX", "RRR", "RRR", "RRR", "RRR"],
+        HoloVstarRare => ["Holo VSTAR Rare", "Rare holo VSTAR", "Rara holo V ASTRO", "Selten holo VSTAR", "Rara holo V-ASTRO", "Rara holo V-ASTRO", "Rara holo V-ASTRO", "RRR", "RRR", "RRR", "RRR"],
+        BreakRare => ["BREAK Rare", "Rare TURBO", "Rara TURBO", "Selten TURBO", "Rara TURBO", "Rara TURBO", "Rara TURBO", "RR", "RR", "RR", "RR"],
+        PrismStarRare => ["Prism Star Rare", "Rare Prisme Étoile", "Rara Prisma Stella", "Selten Prisma-Stern", "Rara Prisma Estrella", "Rara Prisma Estrella", "Rara Prisma Estrela", "R", "R", "R", "R"],
+        SecretRare => ["Secret Rare", "Rare secrète", "Rara segreta", "Selten geheim", "Rara secreta", "Rara secreta", "Rara secreta", "UR", "UR", "UR", "UR"],
+        RainbowRare => ["Rainbow Rare", "Rare arc-en-ciel", "Rara arcobaleno", "Selten Regenbogen", "Rara arcoíris", "Rara arcoíris", "Rara arco-íris", "HR", "HR", "HR", "HR"],
+        ShinyGxRare => ["Shiny GX Rare", "Rare chromatique GX", "Rara cromatica GX", "Selten schillernd GX", "Rara variocolor GX", "Rara variocolor GX", "Rara brilhante GX", "SSR", "SSR", "SSR", "SSR"],
+        AmazingRare => ["Amazing Rare", "Rare magnifique", "Rara straordinaria", "Fantastisch selten", "Rara asombrosa", "Rara asombrosa", "Rara incrível", "A", "A", "A", "A"],
+        RadiantRare => ["Radiant Rare", "Rare radieuse", "Rara radiante", "Strahlend selten", "Rara radiante", "Rara radiante", "Rara radiante", "K", "K", "K", "K"],
+        TrainerGalleryHoloRare => ["Trainer Gallery Holo Rare", "Galerie des Dresseurs rare holo", "Galleria Allenatori rara holo", "Trainer-Galerie selten holo", "Galería de Entrenadores rara holo", "Galería de Entrenadores rara holo", "Galeria de Treinadores rara holo", "CHR", "CHR", "CHR", "CHR"],
+    }
+
+    FoilType {
+        Stamped => ["Stamped", "Tamponné", "Timbrato", "Gestempelt", "Sellado", "Sellado", "Carimbado", "スタンプ", "스탬프", "印章", "印章"],
+        Cosmos => ["Cosmos", "Cosmos", "Cosmo", "Kosmos", "Cosmos", "Cosmos", "Cosmos", "コスモ", "코스모", "宇宙", "宇宙"],
+        FlatSilver => ["Flat Silver", "Argent uni", "Argento piatto", "Flaches Silber", "Plata lisa", "Plata lisa", "Prata lisa", "フラットシルバー", "플랫 실버", "平面銀", "平面银"],
+        SunPillar => ["Sun Pillar", "Colonne solaire", "Colonna solare", "Sonnensäule", "Pilar solar", "Pilar solar", "Pilar solar", "サンピラー", "선 필러", "日柱", "日柱"],
+        SvHolo => ["Scarlet & Violet Holo", "Holo Écarlate et Violet", "Holo Scarlatto e Violetto", "Karmesin & Purpur Holo", "Holo Escarlata y Púrpura", "Holo Escarlata y Púrpura", "Holo Escarlate e Violeta", "スカーレット&バイオレット ホロ", "스칼렛&바이올렛 홀로", "朱&紫 閃卡", "朱&紫 闪卡"],
+        SvUltra => ["Scarlet & Violet Ultra", "Ultra Écarlate et Violet", "Ultra Scarlatto e Violetto", "Karmesin & Purpur Ultra", "Ultra Escarlata y Púrpura", "Ultra Escarlata y Púrpura", "Ultra Escarlate e Violeta", "スカーレット&バイオレット ウルトラ", "스칼렛&바이올렛 울트라", "朱&紫 極致", "朱&紫 极致"],
+        SvUltraScodix => ["Scarlet & Violet Ultra Scodix", "Ultra Scodix Écarlate et Violet", "Ultra Scodix Scarlatto e Violetto", "Karmesin & Purpur Ultra Scodix", "Ultra Scodix Escarlata y Púrpura", "Ultra Scodix Escarlata y Púrpura", "Ultra Scodix Escarlate e Violeta", "スカーレット&バイオレット ウルトラ スコディックス", "스칼렛&바이올렛 울트라 스코딕스", "朱&紫 極致 Scodix", "朱&紫 极致 Scodix"],
+        AceFoil => ["ACE SPEC Foil", "Foil HIGH-TECH", "Foil ASSO TATTICO", "ASS-KLASSE Folie", "Lámina AS TÁCTICO", "Lámina AS TÁCTICO", "Laminado ÁS TÁTICO", "ACE SPEC ホイル", "ACE SPEC 호일", "ACE SPEC 閃卡", "ACE SPEC 闪卡"],
+        Rainbow => ["Rainbow", "Arc-en-ciel", "Arcobaleno", "Regenbogen", "Arcoíris", "Arcoíris", "Arco-íris", "レインボー", "레인보우", "彩虹", "彩虹"],
+        CrackedIce => ["Cracked Ice", "Glace brisée", "Ghiaccio incrinato", "Rissiges Eis", "Hielo agrietado", "Hielo agrietado", "Gelo rachado", "クラックアイス", "크랙 아이스", "碎冰", "碎冰"],
+        UltraGoldRainbow => ["Ultra Gold Rainbow", "Arc-en-ciel or ultra", "Arcobaleno oro ultra", "Ultra-Goldregenbogen", "Arcoíris dorado ultra", "Arcoíris dorado ultra", "Arco-íris dourado ultra", "ウルトラゴールドレインボー", "울트라 골드 레인보우", "極致金彩虹", "极致金彩虹"],
+        Tinsel => ["Tinsel", "Guirlande", "Lamé", "Lametta", "Oropel", "Oropel", "Ouropel", "ティンセル", "틴셀", "金箔", "金箔"],
+    }
+
+    FoilMask {
+        Stamped => ["Stamped", "Tamponné", "Timbrato", "Gestempelt", "Sellado", "Sellado", "Carimbado", "スタンプ", "스탬프", "印章", "印章"],
+        Reverse => ["Reverse Holo", "Reverse holo", "Reverse holo", "Reverse Holo", "Holo inverso", "Holo inverso", "Holo reverso", "リバースホロ", "리버스 홀로", "反向閃卡", "反向闪卡"],
+        Holo => ["Holo", "Holo", "Holo", "Holo", "Holo", "Holo", "Holo", "ホロ", "홀로", "閃卡", "闪卡"],
+        Etched => ["Etched", "Gravé", "Inciso", "Geätzt", "Grabado", "Grabado", "Gravado", "エッチング", "에칭", "蝕刻", "蚀刻"],
+        ColdFoilEtched => ["Cold Foil Etched", "Gravé à froid", "Inciso a freddo", "Kaltfolie geätzt", "Grabado en frío", "Grabado en frío", "Gravado a frio", "コールドフォイルエッチング", "콜드 포일 에칭", "冷燙蝕刻", "冷烫蚀刻"],
+        CastAndCure => ["Cast and Cure", "Coulé et durci", "Colato e indurito", "Gegossen und gehärtet", "Moldeado y curado", "Moldeado y curado", "Moldado e curado", "キャスト&キュア", "캐스트 앤 큐어", "鑄造固化", "铸造固化"],
+        ReverseLaminate => ["Reverse Laminate", "Laminé inversé", "Laminato inverso", "Umgekehrt laminiert", "Laminado inverso", "Laminado inverso", "Laminado reverso", "リバースラミネート", "리버스 라미네이트", "反向覆膜", "反向覆膜"],
+    }
+}
+
+#[test]
+fn every_lang() {
+    use alloc::collections::BTreeSet;
+
+    let langs = [
+        Lang::EnUs,
+        Lang::FrFr,
+        Lang::ItIt,
+        Lang::DeDe,
+        Lang::EsEs,
+        Lang::Es419,
+        Lang::PtBr,
+        Lang::JaJp,
+        Lang::KoKr,
+        Lang::ZhTw,
+        Lang::ZhCn,
+    ];
+    assert_eq!(
+        langs.iter().map(index).collect::<BTreeSet<_>>().len(),
+        langs.len()
+    );
+
+    assert_eq!(EnergyType::Lightning.localized(&Lang::PtBr), "Elétrico");
+    assert_eq!(CardTag::Vstar.localized(&Lang::EsEs), "V-ASTRO");
+    assert_eq!(
+        RarityDesignation::SpecialIllustrationRare.localized(&Lang::JaJp),
+        "SAR"
+    );
+}
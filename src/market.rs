@@ -0,0 +1,300 @@
+//! The markets cards are printed for, and links between the sets of the Asian
+//! markets and the international sets they correspond to.
+//!
+//! Japanese, Korean and Chinese printings are released in their own sets,
+//! with their own set codes (`SV1S`, `SV1V`, ...), numbering and release
+//! dates. A single international set often collects several Asian sets, i.e.
+//! `SVI` collects both `SV1S` and `SV1V`, and a single Asian set may be split
+//! across several international sets.
+//!
+//! Their collector numbers may be printed after the set code, which
+//! [`Parts::set_code`](crate::collector_number::Parts::set_code) splits off,
+//! and their copyright lines name the same holders in a different format,
+//! which [`Copyright::holders`] reads.
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Card, Copyright, Lang};
+
+/// A market cards are printed and released for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Market {
+    /// Every Western language, which share sets, numbering and release dates.
+    International,
+    Japan,
+    Korea,
+    /// Taiwan and Hong Kong.
+    TraditionalChinese,
+    /// Mainland China.
+    SimplifiedChinese,
+}
+
+impl Lang {
+    #[must_use]
+    pub fn market(&self) -> Market {
+        match self {
+            Lang::EnUs
+            | Lang::FrFr
+            | Lang::ItIt
+            | Lang::DeDe
+            | Lang::EsEs
+            | Lang::Es419
+            | Lang::PtBr => Market::International,
+            Lang::JaJp => Market::Japan,
+            Lang::KoKr => Market::Korea,
+            Lang::ZhTw => Market::TraditionalChinese,
+            Lang::ZhCn => Market::SimplifiedChinese,
+        }
+    }
+}
+
+impl Card<'_> {
+    #[must_use]
+    pub fn market(&self) -> Market {
+        self.lang().market()
+    }
+}
+
+impl Copyright<'_> {
+    /// The copyright line as printed, i.e.
+    /// `©2023 Pokémon/Nintendo/Creatures/GAME FREAK`.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    #[must_use]
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The holders named in the copyright line, in order, without the `©` and
+    /// years before them. Reads both the single line printed for most markets
+    /// and the separate notices printed in Chinese, i.e. `©2023 Pokémon.
+    /// ©1995-2023 Nintendo/Creatures Inc./GAME FREAK inc.`, with ASCII or
+    /// full-width separators.
+    pub fn holders(&self) -> impl Iterator<Item = &str> {
+        let notices = self
+            .text
+            .split(['©', 'Ⓒ'])
+            .map(str::trim)
+            .filter(|notice| !notice.is_empty());
+        let count = notices.clone().count();
+
+        notices.enumerate().flat_map(move |(i, notice)| {
+            let notice = notice.trim_start_matches(|c: char| {
+                c.is_ascii_digit() || c.is_whitespace() || matches!(c, '-' | '–' | ',')
+            });
+            // a notice followed by another ends with a full stop
+            let notice = if i + 1 < count {
+                notice.strip_suffix('.').unwrap_or(notice)
+            } else {
+                notice
+            };
+
+            notice
+                .split(['/', '／'])
+                .map(str::trim)
+                .filter(|holder| !holder.is_empty())
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Link {
+    market: Market,
+    set_icon: String,
+    international: String,
+}
+
+/// Links between the sets of each market and the international sets they
+/// correspond to. The export doesn't link sets itself, so the links have to
+/// be provided by whichever data source has them.
+///
+/// Serializes as a list of links:
+///
+/// ```json
+/// [{"market": "JAPAN", "set_icon": "SV1S", "international": "SVI"}]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(from = "Vec<Link>", into = "Vec<Link>")]
+pub struct SetLinks {
+    links: BTreeMap<(Market, String), BTreeSet<String>>,
+}
+
+impl SetLinks {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Link the set `set_icon` of `market` to the international set
+    /// `international`. Links to [`Market::International`] itself are
+    /// ignored, as every international set is its own equivalent.
+    pub fn link(
+        &mut self,
+        market: Market,
+        set_icon: impl Into<String>,
+        international: impl Into<String>,
+    ) {
+        if market != Market::International {
+            self.links
+                .entry((market, set_icon.into()))
+                .or_default()
+                .insert(international.into());
+        }
+    }
+
+    /// The international sets the set `set_icon` of `market` corresponds to,
+    /// in order. An international set corresponds only to itself.
+    pub fn international<'a>(
+        &'a self,
+        market: Market,
+        set_icon: &'a str,
+    ) -> impl Iterator<Item = &'a str> {
+        let links = self
+            .links
+            .get(&(market, String::from(set_icon)))
+            .into_iter()
+            .flatten()
+            .map(String::as_str);
+
+        (market == Market::International)
+            .then_some(set_icon)
+            .into_iter()
+            .chain(links)
+    }
+
+    /// Every set of another market linked to the international set
+    /// `international`, in order.
+    pub fn regional<'a>(
+        &'a self,
+        international: &'a str,
+    ) -> impl Iterator<Item = (Market, &'a str)> {
+        self.links
+            .iter()
+            .filter(move |(_, sets)| sets.contains(international))
+            .map(|((market, set_icon), _)| (*market, set_icon.as_str()))
+    }
+
+    /// The international sets `card` was printed in, or an equivalent of.
+    pub fn for_card<'a>(&'a self, card: &'a Card<'_>) -> impl Iterator<Item = &'a str> {
+        self.international(card.market(), card.set_icon())
+    }
+}
+
+impl From<Vec<Link>> for SetLinks {
+    fn from(links: Vec<Link>) -> Self {
+        let mut set_links = SetLinks::new();
+        for link in links {
+            set_links.link(link.market, link.set_icon, link.international);
+        }
+        set_links
+    }
+}
+
+impl From<SetLinks> for Vec<Link> {
+    fn from(set_links: SetLinks) -> Self {
+        set_links
+            .links
+            .into_iter()
+            .flat_map(|((market, set_icon), sets)| {
+                sets.into_iter().map(move |international| Link {
+                    market,
+                    set_icon: set_icon.clone(),
+                    international,
+                })
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn links() {
+    let links = serde_json::from_str::<SetLinks>(
+        r#"[
+            {"market": "JAPAN", "set_icon": "SV1S", "international": "SVI"},
+            {"market": "JAPAN", "set_icon": "SV1V", "international": "SVI"},
+            {"market": "KOREA", "set_icon": "SV1S", "international": "SVI"},
+            {"market": "JAPAN", "set_icon": "SV4a", "international": "PAF"},
+            {"market": "JAPAN", "set_icon": "SV4a", "international": "PAR"}
+        ]"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        links
+            .international(Market::Japan, "SV4a")
+            .collect::<Vec<_>>(),
+        ["PAF", "PAR"]
+    );
+    assert_eq!(
+        links
+            .international(Market::International, "SVI")
+            .collect::<Vec<_>>(),
+        ["SVI"]
+    );
+    assert_eq!(links.international(Market::Korea, "SV1V").count(), 0);
+    assert_eq!(
+        links.regional("SVI").collect::<Vec<_>>(),
+        [
+            (Market::Japan, "SV1S"),
+            (Market::Japan, "SV1V"),
+            (Market::Korea, "SV1S"),
+        ]
+    );
+
+    let json = serde_json::to_string(&links).unwrap();
+    assert_eq!(serde_json::from_str::<SetLinks>(&json).unwrap(), links);
+}
+
+#[test]
+fn asian_export() {
+    use serde_json::json;
+
+    use crate::{collector_number::Section, fixture::Fixture};
+
+    let card = Fixture::item()
+        .with("lang", json!("ja-JP"))
+        .with("name", json!("ネストボール"))
+        .id("SV1S_61")
+        .number("SV1S 061/078")
+        .with(
+            "copyright",
+            json!({"text": "©2023 Pokémon/Nintendo/Creatures/GAME FREAK", "year": 2023}),
+        )
+        .card();
+
+    assert_eq!(card.lang(), &Lang::JaJp);
+    assert_eq!(card.market(), Market::Japan);
+    assert_eq!(card.tcgl().key().lang_code(), Some("JA"));
+    assert_eq!(card.collector_number().parts().set_code(), Some("SV1S"));
+    assert_eq!(card.section(), Section::Main);
+    assert_eq!(card.validate(), []);
+
+    assert_eq!(
+        card.copyright().unwrap().holders().collect::<Vec<_>>(),
+        ["Pokémon", "Nintendo", "Creatures", "GAME FREAK"]
+    );
+
+    let chinese = Fixture::item()
+        .with("lang", json!("zh-CN"))
+        .with(
+            "copyright",
+            json!({"text": "©2023 Pokémon. ©1995-2023 Nintendo／Creatures Inc.／GAME FREAK inc.", "year": 2023}),
+        )
+        .card();
+    assert_eq!(chinese.market(), Market::SimplifiedChinese);
+    assert_eq!(
+        chinese.copyright().unwrap().holders().collect::<Vec<_>>(),
+        ["Pokémon", "Nintendo", "Creatures Inc.", "GAME FREAK inc."]
+    );
+}
@@ -0,0 +1,350 @@
+//! Conversions from borrowed to owned (`'static`) versions of the model, for
+//! when the cards need to outlive the input they were deserialized from.
+
+use alloc::borrow::Cow;
+
+use crate::{
+    Ability, Artists, Attack, BasicEnergy, Card, CollectorNumber, Copyright, Effect, Energy, Ext,
+    Item, Pokemon, Reminder, RuleBox, SpecialEnergy, Stadium, Supporter, Tcgl, Text, TextBox, Tool,
+    Trainer,
+};
+
+fn owned(s: Cow<'_, str>) -> Cow<'static, str> {
+    Cow::Owned(s.into_owned())
+}
+
+impl Card<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Card<'static> {
+        match self {
+            Card::Pokemon(inner) => Card::Pokemon(inner.into_owned()),
+            Card::Trainer(inner) => Card::Trainer(inner.into_owned()),
+            Card::Energy(inner) => Card::Energy(inner.into_owned()),
+        }
+    }
+}
+
+impl Pokemon<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Pokemon<'static> {
+        Pokemon {
+            name: owned(self.name),
+            subtitle: self.subtitle.map(owned),
+            lang: self.lang,
+            foil: self.foil,
+            size: self.size,
+            back: self.back,
+            artists: self.artists.map(Artists::into_owned),
+            regulation_mark: self.regulation_mark,
+            set_icon: owned(self.set_icon),
+            collector_number: self.collector_number.into_owned(),
+            rarity: self.rarity,
+            copyright: self.copyright.map(Copyright::into_owned),
+            tags: self.tags,
+            stage: self.stage,
+            stage_text: self.stage_text.map(owned),
+            hp: self.hp,
+            weakness: self.weakness,
+            resistance: self.resistance,
+            retreat: self.retreat,
+            flavor_text: self.flavor_text.map(owned),
+            text: self.text.into_iter().map(Text::into_owned).collect(),
+            ext: self.ext.into_owned(),
+            images: self.images,
+            types: self.types,
+        }
+    }
+}
+
+impl Trainer<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Trainer<'static> {
+        match self {
+            Trainer::Item(inner) => Trainer::Item(inner.into_owned()),
+            Trainer::Supporter(inner) => Trainer::Supporter(inner.into_owned()),
+            Trainer::Tool(inner) => Trainer::Tool(inner.into_owned()),
+            Trainer::Stadium(inner) => Trainer::Stadium(inner.into_owned()),
+        }
+    }
+}
+
+impl Item<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Item<'static> {
+        Item {
+            name: owned(self.name),
+            lang: self.lang,
+            foil: self.foil,
+            size: self.size,
+            back: self.back,
+            artists: self.artists.map(Artists::into_owned),
+            regulation_mark: self.regulation_mark,
+            set_icon: owned(self.set_icon),
+            collector_number: self.collector_number.into_owned(),
+            rarity: self.rarity,
+            copyright: self.copyright.map(Copyright::into_owned),
+            tags: self.tags,
+            stage_text: self.stage_text.map(owned),
+            hp: self.hp,
+            flavor_text: self.flavor_text.map(owned),
+            text: self.text.into_iter().map(Text::into_owned).collect(),
+            ext: self.ext.into_owned(),
+            images: self.images,
+        }
+    }
+}
+
+impl Supporter<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Supporter<'static> {
+        Supporter {
+            name: owned(self.name),
+            subtitle: self.subtitle.map(owned),
+            lang: self.lang,
+            foil: self.foil,
+            size: self.size,
+            back: self.back,
+            artists: self.artists.map(Artists::into_owned),
+            regulation_mark: self.regulation_mark,
+            set_icon: owned(self.set_icon),
+            collector_number: self.collector_number.into_owned(),
+            rarity: self.rarity,
+            copyright: self.copyright.map(Copyright::into_owned),
+            tags: self.tags,
+            text: self.text.into_iter().map(Text::into_owned).collect(),
+            ext: self.ext.into_owned(),
+            images: self.images,
+        }
+    }
+}
+
+impl Tool<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Tool<'static> {
+        Tool {
+            name: owned(self.name),
+            lang: self.lang,
+            foil: self.foil,
+            size: self.size,
+            back: self.back,
+            artists: self.artists.map(Artists::into_owned),
+            regulation_mark: self.regulation_mark,
+            set_icon: owned(self.set_icon),
+            collector_number: self.collector_number.into_owned(),
+            rarity: self.rarity,
+            copyright: self.copyright.map(Copyright::into_owned),
+            tags: self.tags,
+            flavor_text: self.flavor_text.map(owned),
+            text: self.text.into_iter().map(Text::into_owned).collect(),
+            ext: self.ext.into_owned(),
+            images: self.images,
+        }
+    }
+}
+
+impl Stadium<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Stadium<'static> {
+        Stadium {
+            name: owned(self.name),
+            lang: self.lang,
+            foil: self.foil,
+            size: self.size,
+            back: self.back,
+            artists: self.artists.map(Artists::into_owned),
+            regulation_mark: self.regulation_mark,
+            set_icon: owned(self.set_icon),
+            collector_number: self.collector_number.into_owned(),
+            rarity: self.rarity,
+            copyright: self.copyright.map(Copyright::into_owned),
+            tags: self.tags,
+            flavor_text: self.flavor_text.map(owned),
+            text: self.text.into_iter().map(Text::into_owned).collect(),
+            ext: self.ext.into_owned(),
+            images: self.images,
+        }
+    }
+}
+
+impl Energy<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Energy<'static> {
+        match self {
+            Energy::Basic(inner) => Energy::Basic(inner.into_owned()),
+            Energy::Special(inner) => Energy::Special(inner.into_owned()),
+        }
+    }
+}
+
+impl BasicEnergy<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> BasicEnergy<'static> {
+        BasicEnergy {
+            name: owned(self.name),
+            lang: self.lang,
+            foil: self.foil,
+            size: self.size,
+            back: self.back,
+            set_icon: owned(self.set_icon),
+            collector_number: self.collector_number.into_owned(),
+            rarity: self.rarity,
+            copyright: self.copyright.map(Copyright::into_owned),
+            tags: self.tags,
+            ext: self.ext.into_owned(),
+            images: self.images,
+            types: self.types,
+        }
+    }
+}
+
+impl SpecialEnergy<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> SpecialEnergy<'static> {
+        SpecialEnergy {
+            name: owned(self.name),
+            lang: self.lang,
+            foil: self.foil,
+            size: self.size,
+            back: self.back,
+            regulation_mark: self.regulation_mark,
+            set_icon: owned(self.set_icon),
+            collector_number: self.collector_number.into_owned(),
+            rarity: self.rarity,
+            copyright: self.copyright.map(Copyright::into_owned),
+            tags: self.tags,
+            flavor_text: self.flavor_text.map(owned),
+            text: self.text.into_iter().map(Text::into_owned).collect(),
+            ext: self.ext.into_owned(),
+            images: self.images,
+        }
+    }
+}
+
+impl Artists<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Artists<'static> {
+        Artists {
+            text: owned(self.text),
+            list: self.list.into_iter().map(owned).collect(),
+        }
+    }
+}
+
+impl CollectorNumber<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> CollectorNumber<'static> {
+        CollectorNumber {
+            full: owned(self.full),
+            numerator: owned(self.numerator),
+            denominator: self.denominator.map(owned),
+            numeric: self.numeric,
+        }
+    }
+}
+
+impl Copyright<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Copyright<'static> {
+        Copyright {
+            text: owned(self.text),
+            year: self.year,
+        }
+    }
+}
+
+impl Ext<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Ext<'static> {
+        Ext {
+            tcgl: self.tcgl.into_owned(),
+        }
+    }
+}
+
+impl Tcgl<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Tcgl<'static> {
+        Tcgl {
+            archetype_id: self.archetype_id,
+            card_id: self.card_id.into_owned(),
+            key: self.key.into_owned(),
+            long_form_id: self.long_form_id.into_owned(),
+            reldate: self.reldate,
+        }
+    }
+}
+
+impl Text<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Text<'static> {
+        match self {
+            Text::Attack(inner) => Text::Attack(inner.into_owned()),
+            Text::Ability(inner) => Text::Ability(inner.into_owned()),
+            Text::RuleBox(inner) => Text::RuleBox(inner.into_owned()),
+            Text::Effect(inner) => Text::Effect(inner.into_owned()),
+            Text::TextBox(inner) => Text::TextBox(inner.into_owned()),
+            Text::Reminder(inner) => Text::Reminder(inner.into_owned()),
+        }
+    }
+}
+
+impl Attack<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Attack<'static> {
+        Attack {
+            name: owned(self.name),
+            text: self.text.map(owned),
+            cost: self.cost,
+            damage: self.damage,
+        }
+    }
+}
+
+impl Ability<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Ability<'static> {
+        Ability {
+            name: owned(self.name),
+            text: owned(self.text),
+        }
+    }
+}
+
+impl RuleBox<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> RuleBox<'static> {
+        RuleBox {
+            name: owned(self.name),
+            text: owned(self.text),
+        }
+    }
+}
+
+impl Effect<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Effect<'static> {
+        Effect {
+            name: owned(self.name),
+            text: owned(self.text),
+        }
+    }
+}
+
+impl TextBox<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> TextBox<'static> {
+        TextBox {
+            name: self.name.map(owned),
+            text: owned(self.text),
+        }
+    }
+}
+
+impl Reminder<'_> {
+    #[must_use]
+    pub fn into_owned(self) -> Reminder<'static> {
+        Reminder {
+            text: owned(self.text),
+        }
+    }
+}
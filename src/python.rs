@@ -0,0 +1,281 @@
+//! Python bindings, built with [PyO3].
+//!
+//! Enable the `python` feature and build the extension module with
+//! [maturin]:
+//!
+//! ```sh
+//! maturin develop --features python
+//! ```
+//!
+//! ```python
+//! import malie
+//!
+//! cards = malie.load("svi_en-US.json")
+//! grass = [card.name for card in cards if malie.EnergyType.GRASS in card.types]
+//! rows = malie.to_dicts(cards)
+//! ```
+//!
+//! [PyO3]: https://pyo3.rs
+//! [maturin]: https://www.maturin.rs
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use std::{fs, path::PathBuf};
+
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyBool, PyDict, PyList, PyString},
+};
+use serde_json::Value;
+
+use crate::{EnergyType, Lang, RarityDesignation, serde_name};
+
+/// Parse the contents of an export file.
+///
+/// # Errors
+///
+/// Raises `ValueError` if `json` is not a valid export.
+#[pyfunction]
+pub fn loads(json: &str) -> PyResult<Vec<Card>> {
+    let cards = serde_json::from_str::<Vec<crate::Card>>(json).map_err(value_error)?;
+
+    Ok(cards
+        .into_iter()
+        .map(|card| Card(card.into_owned()))
+        .collect())
+}
+
+/// Read and parse an export file.
+///
+/// # Errors
+///
+/// Raises `OSError` if the file could not be read, or `ValueError` if it is
+/// not a valid export.
+#[pyfunction]
+pub fn load(path: PathBuf) -> PyResult<Vec<Card>> {
+    loads(&fs::read_to_string(path)?)
+}
+
+/// Convert `cards` to a list of dicts, in the same shape as the export JSON.
+///
+/// # Errors
+///
+/// Raises if a card could not be converted.
+#[pyfunction]
+#[expect(
+    clippy::needless_pass_by_value,
+    reason = "extracted from a Python list"
+)]
+pub fn to_dicts<'py>(
+    py: Python<'py>,
+    cards: Vec<PyRef<'py, Card>>,
+) -> PyResult<Bound<'py, PyList>> {
+    PyList::new(
+        py,
+        cards
+            .iter()
+            .map(|card| card.to_dict(py))
+            .collect::<PyResult<Vec<_>>>()?,
+    )
+}
+
+/// A single card from an export.
+#[pyclass(frozen, module = "malie")]
+pub struct Card(crate::Card<'static>);
+
+#[pymethods]
+impl Card {
+    /// Parse a single card.
+    ///
+    /// # Errors
+    ///
+    /// Raises `ValueError` if `json` is not a valid card.
+    #[staticmethod]
+    pub fn from_json(json: &str) -> PyResult<Self> {
+        serde_json::from_str::<crate::Card>(json)
+            .map(|card| Self(card.into_owned()))
+            .map_err(value_error)
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn card_type(&self) -> &'static str {
+        self.0.type_tags().0
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn subtype(&self) -> Option<&'static str> {
+        self.0.type_tags().1
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn lang(&self) -> Lang {
+        self.0.lang().clone()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn set_icon(&self) -> &str {
+        self.0.set_icon()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn collector_number(&self) -> &str {
+        &self.0.collector_number().full
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn rarity(&self) -> Option<RarityDesignation> {
+        self.0.rarity().map(|rarity| rarity.designation.clone())
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn regulation_mark(&self) -> Option<&'static str> {
+        self.0.regulation_mark().map(serde_name::variant)
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn hp(&self) -> Option<u16> {
+        self.0.hp().map(core::num::NonZeroU16::get)
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn types(&self) -> Vec<EnergyType> {
+        self.0.types().to_vec()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn tags(&self) -> Vec<&'static str> {
+        self.0.tags().iter().map(serde_name::variant).collect()
+    }
+
+    #[getter]
+    #[must_use]
+    pub fn card_id(&self) -> &str {
+        self.0.tcgl().card_id.as_str()
+    }
+
+    /// This card as a dict, in the same shape as the export JSON.
+    ///
+    /// # Errors
+    ///
+    /// Raises if the card could not be converted.
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        to_py(py, &serde_json::to_value(&self.0).map_err(value_error)?)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Card(name={:?}, card_id={:?})",
+            self.0.name(),
+            self.0.tcgl().card_id.as_str()
+        )
+    }
+}
+
+macro_rules! serde_value {
+    ($($ty:ty),*) => {$(
+        #[pymethods]
+        impl $ty {
+            /// The value used for this variant in the export JSON.
+            #[getter]
+            #[must_use]
+            pub fn value(&self) -> &'static str {
+                serde_name::variant(self)
+            }
+
+            fn __str__(&self) -> &'static str {
+                self.value()
+            }
+        }
+    )*};
+}
+
+serde_value!(Lang, EnergyType, RarityDesignation);
+
+#[expect(clippy::needless_pass_by_value, reason = "used with `map_err`")]
+fn value_error(err: serde_json::Error) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+fn to_py<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    Ok(match value {
+        Value::Null => py.None().into_bound(py),
+        Value::Bool(b) => PyBool::new(py, *b).to_owned().into_any(),
+        Value::Number(n) => {
+            if let Some(n) = n.as_u64() {
+                n.into_pyobject(py)?.into_any()
+            } else if let Some(n) = n.as_i64() {
+                n.into_pyobject(py)?.into_any()
+            } else {
+                n.as_f64().into_pyobject(py)?.into_any()
+            }
+        }
+        Value::String(s) => PyString::new(py, s).into_any(),
+        Value::Array(values) => PyList::new(
+            py,
+            values
+                .iter()
+                .map(|value| to_py(py, value))
+                .collect::<PyResult<Vec<_>>>()?,
+        )?
+        .into_any(),
+        Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (key, value) in map {
+                dict.set_item(key, to_py(py, value)?)?;
+            }
+            dict.into_any()
+        }
+    })
+}
+
+/// The `malie` extension module.
+#[pymodule(name = "malie")]
+pub mod module {
+    #[pymodule_export]
+    use super::{Card, load, loads, to_dicts};
+    #[pymodule_export]
+    use crate::{EnergyType, Lang, RarityDesignation};
+}
+
+#[test]
+fn accessors() {
+    Python::initialize();
+    Python::attach(|py| {
+        let card =
+            Card::from_json(&crate::fixture::Fixture::basic_energy().value().to_string()).unwrap();
+
+        assert_eq!(card.subtype(), Some("BASIC"));
+        assert_eq!(card.types(), [EnergyType::Grass]);
+        assert_eq!(card.lang().value(), "en-US");
+
+        let dict = card.to_dict(py).unwrap();
+        assert_eq!(
+            dict.get_item("ext")
+                .and_then(|ext| ext.get_item("tcgl"))
+                .and_then(|tcgl| tcgl.get_item("cardID"))
+                .and_then(|id| id.extract::<String>())
+                .unwrap(),
+            "SVE_1"
+        );
+    });
+}
@@ -2,12 +2,13 @@ use core::fmt;
 
 use serde::{Deserializer, Serializer, de};
 use time::{
-    PrimitiveDateTime,
+    OffsetDateTime, UtcDateTime, UtcOffset,
     error::Format,
     format_description::{
         BorrowedFormatItem, Component,
         modifier::{
-            Day, Hour, Minute, Month, MonthRepr, Padding, Second, Year, YearRange, YearRepr,
+            Day, Hour, Minute, Month, MonthRepr, OffsetHour, OffsetMinute, Padding, Second, Year,
+            YearRange, YearRepr,
         },
     },
 };
@@ -55,38 +56,95 @@ const ITEMS: &[BorrowedFormatItem<'_>] = &[
         value.padding = Padding::Zero;
         value
     })),
-    BorrowedFormatItem::Literal(b"+00:00"),
+    BorrowedFormatItem::Component(Component::OffsetHour({
+        let mut value = OffsetHour::default();
+        value.sign_is_mandatory = true;
+        value.padding = Padding::Zero;
+        value
+    })),
+    BorrowedFormatItem::Literal(b":"),
+    BorrowedFormatItem::Component(Component::OffsetMinute({
+        let mut value = OffsetMinute::default();
+        value.padding = Padding::Zero;
+        value
+    })),
 ];
 
+/// The only offset a reldate is exported with.
+const UTC_SUFFIX: &str = "+00:00";
+
 struct Visitor;
 
 impl de::Visitor<'_> for Visitor {
-    type Value = PrimitiveDateTime;
+    type Value = UtcDateTime;
     fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            concat!("a(n) `", "PrimitiveDateTime", "` in the format \"{}\"",),
+            concat!("a(n) `", "UtcDateTime", "` in the format \"{}\"",),
             "[year]-[month]-[day] [hour]:[minute]:[second]+00:00"
         )
     }
-    fn visit_str<E: de::Error>(self, value: &str) -> Result<PrimitiveDateTime, E> {
-        PrimitiveDateTime::parse(value, ITEMS).map_err(E::custom)
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<UtcDateTime, E> {
+        // any other offset would be normalized to UTC and lost when serialized
+        if !value.ends_with(UTC_SUFFIX) {
+            return Err(E::invalid_value(de::Unexpected::Str(value), &self));
+        }
+
+        OffsetDateTime::parse(value, ITEMS)
+            .map(OffsetDateTime::to_utc)
+            .map_err(E::custom)
     }
 }
 
-pub fn serialize<S: Serializer>(
-    datetime: &PrimitiveDateTime,
-    serializer: S,
-) -> Result<S::Ok, S::Error> {
+#[cfg(feature = "schemars")]
+pub struct Reldate;
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Reldate {
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        "Reldate".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "[year]-[month]-[day] [hour]:[minute]:[second]+00:00",
+            "pattern": r"^\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\+00:00$",
+        })
+    }
+}
+
+/// Serialized in UTC, i.e. with a `+00:00` offset.
+pub fn serialize<S: Serializer>(datetime: &UtcDateTime, serializer: S) -> Result<S::Ok, S::Error> {
     use ::serde::Serialize;
     datetime
+        .to_offset(UtcOffset::UTC)
         .format(ITEMS)
         .map_err(Format::into_invalid_serde_value::<S>)?
         .serialize(serializer)
 }
 
-pub fn deserialize<'a, D: Deserializer<'a>>(
-    deserializer: D,
-) -> Result<PrimitiveDateTime, D::Error> {
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<UtcDateTime, D::Error> {
     deserializer.deserialize_str(Visitor)
 }
+
+#[test]
+fn utc_only() {
+    use serde_json::Value;
+
+    let parse = |s: &str| deserialize(Value::from(s));
+
+    let datetime = parse("2023-03-31 09:30:00+00:00").unwrap();
+    assert_eq!(
+        serialize(&datetime, serde_json::value::Serializer).unwrap(),
+        "2023-03-31 09:30:00+00:00"
+    );
+
+    // other offsets would be lossy to roundtrip
+    for offset in ["+09:00", "-05:00", "-00:00"] {
+        let error = parse(&alloc::format!("2023-03-31 09:00:00{offset}")).unwrap_err();
+        assert!(error.to_string().contains("+00:00"), "{error}");
+    }
+    assert!(parse("2023-03-31 09:00:00").is_err());
+    assert!(parse("2023-02-30 09:00:00+00:00").is_err());
+}
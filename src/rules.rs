@@ -0,0 +1,275 @@
+//! Game rules implied by the tags and stage of a card, which its rule box
+//! only states as text: how many Prize cards it gives up, how many copies a
+//! deck can have, and which attacks are GX attacks.
+
+use crate::{Attack, Card, CardTag, Energy, Stage};
+
+/// The most copies of a card with the same name a deck can have.
+const COPY_LIMIT: u8 = 4;
+
+impl Card<'_> {
+    /// The number of Prize cards the opponent takes when this Pokémon is
+    /// Knocked Out, or `None` if the card isn't a Pokémon.
+    #[must_use]
+    pub fn prize_cards(&self) -> Option<u8> {
+        let Card::Pokemon(pokemon) = self else {
+            return None;
+        };
+
+        let tags = self.tags();
+        Some(
+            if tags.contains(&CardTag::TagTeam)
+                || tags.contains(&CardTag::Vmax)
+                || tags.contains(&CardTag::VUnion)
+                || matches!(pokemon.stage, Stage::Vmax | Stage::VUnion)
+                // Mega Evolution Pokémon ex, unlike the Mega Evolution
+                // Pokémon-EX of XY
+                || (tags.contains(&CardTag::MegaEvolution) && tags.contains(&CardTag::ExLower))
+            {
+                3
+            } else if tags.iter().any(|tag| {
+                matches!(
+                    tag,
+                    CardTag::ExLower | CardTag::ExUpper | CardTag::Gx | CardTag::V | CardTag::Vstar
+                )
+            }) {
+                2
+            } else {
+                1
+            },
+        )
+    }
+
+    /// The most copies with the same name a deck can have, or `None` if it
+    /// can have any number, i.e. of basic energy. A deck can have only one
+    /// [`CardTag::PrismStar`] card of each name.
+    #[must_use]
+    pub fn copy_limit(&self) -> Option<u8> {
+        if matches!(self, Card::Energy(Energy::Basic(_))) {
+            None
+        } else if self.tags().contains(&CardTag::PrismStar) {
+            Some(1)
+        } else {
+            Some(COPY_LIMIT)
+        }
+    }
+
+    /// The tag of which a deck can have only one card in total, regardless
+    /// of name, i.e. [`CardTag::AceSpec`] or [`CardTag::Radiant`].
+    #[must_use]
+    pub fn one_per_deck(&self) -> Option<&CardTag> {
+        self.tags()
+            .iter()
+            .find(|tag| matches!(tag, CardTag::AceSpec | CardTag::Radiant))
+    }
+
+    /// Whether the card goes to the Lost Zone rather than the discard pile,
+    /// as [`CardTag::PrismStar`] cards do.
+    #[must_use]
+    pub fn is_lost_when_discarded(&self) -> bool {
+        self.tags().contains(&CardTag::PrismStar)
+    }
+
+    /// The GX attacks of the card, of which a player can use only one per
+    /// game. Only Pokémon-GX and TAG TEAM cards have GX attacks, which the
+    /// export doesn't mark other than by the `GX` at the end of their name.
+    pub fn gx_attacks(&self) -> impl Iterator<Item = &Attack<'_>> {
+        let tags = self.tags();
+        let has_gx = tags.contains(&CardTag::Gx) || tags.contains(&CardTag::TagTeam);
+
+        self.attacks()
+            .filter(move |attack| has_gx && attack.name.ends_with("GX"))
+    }
+}
+
+/// Cards from before Scarlet & Violet: Charizard VMAX, Pikachu & Zekrom-GX,
+/// Cyrus ◇, Xerneas-EX and Greninja BREAK. Only Charizard VMAX has a
+/// regulation mark.
+#[cfg(test)]
+fn earlier_era_fixtures() -> [crate::fixture::Fixture; 5] {
+    use serde_json::json;
+
+    use crate::fixture::Fixture;
+
+    let rarity = |designation: &str| json!({"designation": designation, "icon": "SOLID_STAR"});
+    let rule_box = |name: &str, text: &str| json!({"kind": "RULE_BOX", "name": name, "text": text});
+
+    [
+        // Sword & Shield
+        Fixture::pokemon()
+            .with("name", json!("Charizard VMAX"))
+            .id("DAA_20")
+            .number("020/189")
+            .reldate("2020-08-14")
+            .with("regulation_mark", json!("D"))
+            .with("rarity", rarity("HOLO_VMAX_RARE"))
+            .with("stage", json!("VMAX"))
+            .with("tags", json!(["VMAX"]))
+            .with("hp", json!(330))
+            .with("types", json!(["FIRE"]))
+            .with(
+                "text",
+                json!([
+                    {"kind": "ATTACK", "name": "G-Max Wildfire", "cost": ["FIRE", "FIRE", "COLORLESS", "COLORLESS", "COLORLESS"], "damage": {"amount": 300}},
+                    rule_box("VMAX rule", "When your Pokémon VMAX is Knocked Out, your opponent takes 3 Prize cards.")
+                ]),
+            ),
+        // Sun & Moon, printed before regulation marks
+        Fixture::pokemon()
+            .with("name", json!("Pikachu & Zekrom-GX"))
+            .id("TEU_33")
+            .number("033/181")
+            .reldate("2019-02-01")
+            .without("regulation_mark")
+            .with("rarity", rarity("HOLO_GX_RARE"))
+            .with("tags", json!(["GX", "TAG_TEAM"]))
+            .with("hp", json!(240))
+            .with(
+                "text",
+                json!([
+                    {"kind": "ATTACK", "name": "Full Blitz", "cost": ["LIGHTNING", "LIGHTNING", "LIGHTNING"], "damage": {"amount": 150}},
+                    {"kind": "ATTACK", "name": "Tag Bolt-GX", "cost": ["LIGHTNING", "LIGHTNING", "LIGHTNING"], "damage": {"amount": 200}},
+                    rule_box("TAG TEAM rule", "When your TAG TEAM is Knocked Out, your opponent takes 3 Prize cards."),
+                ]),
+            ),
+        Fixture::item()
+            .with("subtype", json!("SUPPORTER"))
+            .with("name", json!("Cyrus ◇"))
+            .id("UPR_120")
+            .number("120/156")
+            .reldate("2018-05-04")
+            .without("regulation_mark")
+            .with("rarity", rarity("PRISM_STAR_RARE"))
+            .with("tags", json!(["PRISM_STAR"]))
+            .with(
+                "text",
+                json!([
+                    {"kind": "EFFECT", "name": "Supporter", "text": "Your opponent shuffles all of their Benched Pokémon into their deck."},
+                    rule_box("Prism Star rule", "You can't have more than 1 ◇ card with the same name in your deck. If a ◇ card would go to the discard pile, put it in the Lost Zone instead.")
+                ]),
+            ),
+        // XY
+        Fixture::pokemon()
+            .with("name", json!("Xerneas-EX"))
+            .id("XY_97")
+            .number("97/146")
+            .reldate("2014-02-05")
+            .without("regulation_mark")
+            .with("rarity", rarity("HOLO_EX_RARE"))
+            .with("tags", json!(["EX_UPPER"]))
+            .with("hp", json!(170))
+            .with("types", json!(["FAIRY"]))
+            .with(
+                "text",
+                json!([
+                    {"kind": "ATTACK", "name": "X Blast", "cost": ["FAIRY", "FAIRY", "COLORLESS"], "damage": {"amount": 100}},
+                    rule_box("Pokémon-EX rule", "When a Pokémon-EX has been Knocked Out, your opponent takes 2 Prize cards.")
+                ]),
+            ),
+        Fixture::pokemon()
+            .with("name", json!("Greninja BREAK"))
+            .id("BKP_41")
+            .number("41/122")
+            .reldate("2016-02-03")
+            .without("regulation_mark")
+            .with("rarity", rarity("BREAK_RARE"))
+            .with("stage", json!("BREAK"))
+            .with("tags", json!(["BREAK"]))
+            .with("hp", json!(170))
+            .with("types", json!(["WATER"]))
+            .with(
+                "text",
+                json!([
+                    {"kind": "ABILITY", "name": "Giant Water Shuriken", "text": "Once during your turn, you may discard a Water Energy card from your hand."},
+                    rule_box("BREAK rule", "Greninja BREAK retains the attacks, Abilities, Weakness, Resistance, and Retreat Cost of its previous Evolution.")
+                ]),
+            ),
+    ]
+}
+
+#[test]
+fn earlier_eras() {
+    use alloc::vec::Vec;
+
+    use crate::{EnergyType, RarityDesignation, fixture::Fixture, serde_name};
+
+    let fixtures = earlier_era_fixtures();
+    let cards = fixtures.iter().map(Fixture::card).collect::<Vec<_>>();
+
+    // every new value serializes back to the name it was read from
+    for (fixture, card) in fixtures.iter().zip(&cards) {
+        assert_eq!(&serde_json::to_value(card).unwrap(), fixture.value());
+        assert_eq!(card.validate(), []);
+    }
+
+    let [charizard, pikachu_zekrom, cyrus, xerneas, greninja] = &cards[..] else {
+        unreachable!()
+    };
+
+    assert_eq!(
+        charizard.rarity().unwrap().designation,
+        RarityDesignation::HoloVmaxRare
+    );
+    assert!(pikachu_zekrom.regulation_mark().is_none());
+    assert_eq!(xerneas.types(), [EnergyType::Fairy]);
+
+    assert_eq!(charizard.prize_cards(), Some(3));
+    assert_eq!(pikachu_zekrom.prize_cards(), Some(3));
+    assert_eq!(xerneas.prize_cards(), Some(2));
+    assert_eq!(greninja.prize_cards(), Some(1));
+    assert_eq!(cyrus.prize_cards(), None);
+
+    assert_eq!(cyrus.copy_limit(), Some(1));
+    assert!(cyrus.is_lost_when_discarded());
+    assert_eq!(xerneas.copy_limit(), Some(4));
+    assert_eq!(
+        pikachu_zekrom
+            .gx_attacks()
+            .map(|attack| attack.name.as_ref())
+            .collect::<Vec<_>>(),
+        ["Tag Bolt-GX"]
+    );
+
+    assert_eq!(serde_name::variant(&Stage::VUnion), "V_UNION");
+    assert_eq!(serde_name::variant(&Stage::Vstar), "VSTAR");
+    assert_eq!(serde_name::variant(&Stage::Restored), "RESTORED");
+    assert_eq!(serde_name::variant(&CardTag::SingleStrike), "SINGLE_STRIKE");
+    assert_eq!(
+        serde_name::variant(&RarityDesignation::TrainerGalleryHoloRare),
+        "TRAINER_GALLERY_HOLO_RARE"
+    );
+}
+
+#[test]
+fn prize_cards() {
+    use serde_json::json;
+
+    use crate::fixture::Fixture;
+
+    let pokemon = |tags: serde_json::Value| Fixture::pokemon().with("tags", tags).card();
+
+    assert_eq!(pokemon(json!([])).prize_cards(), Some(1));
+    assert_eq!(pokemon(json!(["EX_LOWER"])).prize_cards(), Some(2));
+    assert_eq!(pokemon(json!(["EX_LOWER", "TERA"])).prize_cards(), Some(2));
+    // Mega Evolution Pokémon ex and Pokémon-EX
+    assert_eq!(
+        pokemon(json!(["MEGA_EVOLUTION", "EX_LOWER"])).prize_cards(),
+        Some(3)
+    );
+    assert_eq!(
+        pokemon(json!(["MEGA_EVOLUTION", "EX_UPPER"])).prize_cards(),
+        Some(2)
+    );
+    assert_eq!(pokemon(json!(["V_UNION"])).prize_cards(), Some(3));
+    assert_eq!(Fixture::item().card().prize_cards(), None);
+
+    // an attack named like a GX attack on a card that can't have one
+    let not_gx = Fixture::pokemon()
+        .with(
+            "text",
+            json!([{"kind": "ATTACK", "name": "Mach Bolt-GX", "cost": ["LIGHTNING"], "damage": {"amount": 10}}]),
+        )
+        .card();
+    assert_eq!(not_gx.attacks().count(), 1);
+    assert_eq!(not_gx.gx_attacks().count(), 0);
+}
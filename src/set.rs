@@ -0,0 +1,300 @@
+//! A registry of the sets in a collection of exports, derived from the
+//! `set_icon` of each card.
+//!
+//! Set names aren't part of the export, so they have to be added from another
+//! source, i.e. the names listed in the [`Index`] of the exports.
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+
+use time::{Date, UtcDateTime, macros::date};
+
+use crate::{Card, Lang, collector_number::Section, index::Index, market::Market};
+
+/// A series of sets, named after the first set released in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Series {
+    /// Every series before Black & White.
+    Earlier,
+    BlackWhite,
+    Xy,
+    SunMoon,
+    SwordShield,
+    ScarletViolet,
+    MegaEvolution,
+}
+
+impl Series {
+    /// The series a set released on `date` in `market` belongs to. Every
+    /// market other than [`Market::International`] uses the Japanese release
+    /// schedule, which the Korean and Chinese ones roughly follow.
+    #[must_use]
+    pub fn of(date: Date, market: Market) -> Self {
+        let starts = if market == Market::International {
+            [
+                (date!(2025 - 09 - 26), Series::MegaEvolution),
+                (date!(2023 - 03 - 31), Series::ScarletViolet),
+                (date!(2020 - 02 - 07), Series::SwordShield),
+                (date!(2017 - 02 - 03), Series::SunMoon),
+                (date!(2014 - 02 - 05), Series::Xy),
+                (date!(2011 - 04 - 25), Series::BlackWhite),
+            ]
+        } else {
+            [
+                (date!(2025 - 08 - 01), Series::MegaEvolution),
+                (date!(2023 - 01 - 20), Series::ScarletViolet),
+                (date!(2019 - 12 - 06), Series::SwordShield),
+                (date!(2016 - 12 - 09), Series::SunMoon),
+                (date!(2013 - 12 - 06), Series::Xy),
+                (date!(2010 - 09 - 18), Series::BlackWhite),
+            ]
+        };
+
+        starts
+            .into_iter()
+            .find_map(|(start, series)| (date >= start).then_some(series))
+            .unwrap_or(Series::Earlier)
+    }
+}
+
+/// A single set of a single market.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Set<'c> {
+    code: &'c str,
+    market: Market,
+    reldate: UtcDateTime,
+    total: Option<u16>,
+    secret_rares: usize,
+    names: BTreeMap<Lang, String>,
+}
+
+impl<'c> Set<'c> {
+    /// The `set_icon` of the cards in the set, i.e. `SVI`.
+    #[must_use]
+    pub fn code(&self) -> &'c str {
+        self.code
+    }
+
+    #[must_use]
+    pub fn market(&self) -> Market {
+        self.market
+    }
+
+    /// The release date of the earliest card in the set.
+    #[must_use]
+    pub fn reldate(&self) -> UtcDateTime {
+        self.reldate
+    }
+
+    #[must_use]
+    pub fn series(&self) -> Series {
+        Series::of(self.reldate.date(), self.market)
+    }
+
+    /// The size of the set printed in each collector number, i.e. the `198`
+    /// of `081/198`, or `None` if no card is numbered against a size.
+    #[must_use]
+    pub fn total(&self) -> Option<u16> {
+        self.total
+    }
+
+    /// The number of distinct cards numbered past [`total`](Self::total).
+    #[must_use]
+    pub fn secret_rares(&self) -> usize {
+        self.secret_rares
+    }
+
+    /// The name of the set in `lang`, if it has been added.
+    #[must_use]
+    pub fn name(&self, lang: &Lang) -> Option<&str> {
+        self.names.get(lang).map(String::as_str)
+    }
+}
+
+/// Every set in a collection of cards, in chronological order.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Sets<'c> {
+    sets: Vec<Set<'c>>,
+    index: BTreeMap<(Market, &'c str), usize>,
+}
+
+impl<'c> Sets<'c> {
+    #[must_use]
+    pub fn new(cards: &'c [Card<'_>]) -> Self {
+        #[derive(Default)]
+        struct Acc<'c> {
+            reldate: Option<UtcDateTime>,
+            totals: BTreeMap<u16, usize>,
+            secret_rares: BTreeSet<&'c str>,
+        }
+
+        let mut accs = BTreeMap::<(Market, &str), Acc>::new();
+        for card in cards {
+            let acc = accs.entry((card.market(), card.set_icon())).or_default();
+            let tcgl = card.tcgl();
+
+            acc.reldate = Some(acc.reldate.map_or(tcgl.reldate, |r| r.min(tcgl.reldate)));
+
+            let parts = card.collector_number().parts();
+            if let Some(total) = parts
+                .denominator()
+                .filter(|_| parts.section() != Section::Subset)
+                .and_then(|denominator| denominator.parse().ok())
+            {
+                *acc.totals.entry(total).or_default() += 1;
+            }
+
+            if parts.section() == Section::Secret {
+                acc.secret_rares.insert(tcgl.card_id.as_str());
+            }
+        }
+
+        let mut sets = accs
+            .into_iter()
+            .filter_map(|((market, code), acc)| {
+                Some(Set {
+                    code,
+                    market,
+                    reldate: acc.reldate?,
+                    // the most common size, in case of any misprinted numbers
+                    total: acc
+                        .totals
+                        .into_iter()
+                        .max_by_key(|&(_, count)| count)
+                        .map(|(total, _)| total),
+                    secret_rares: acc.secret_rares.len(),
+                    names: BTreeMap::new(),
+                })
+            })
+            .collect::<Vec<_>>();
+        sets.sort_by_key(|set| (set.reldate, set.market, set.code));
+
+        let index = sets
+            .iter()
+            .enumerate()
+            .map(|(i, set)| ((set.market, set.code), i))
+            .collect();
+
+        Self { sets, index }
+    }
+
+    /// Add the name of the set `code` in `lang`, returning whether the set is
+    /// in the registry.
+    pub fn add_name(&mut self, code: &str, lang: Lang, name: impl Into<String>) -> bool {
+        let Some(&i) = self.index.get(&(lang.market(), code)) else {
+            return false;
+        };
+
+        self.sets[i].names.insert(lang, name.into());
+        true
+    }
+
+    /// Add the name of every set in `index` to the set its export file is
+    /// named after, returning the number of names added. Set codes are matched
+    /// ignoring case, as file names are in lower case.
+    pub fn add_names(&mut self, index: &Index<'_>) -> usize {
+        let mut added = 0;
+        for (lang, name, entry) in index.iter() {
+            let Some(code) = entry.set_code() else {
+                continue;
+            };
+
+            let market = lang.market();
+            if let Some(set) = self
+                .sets
+                .iter_mut()
+                .find(|set| set.market == market && set.code.eq_ignore_ascii_case(code))
+            {
+                set.names.insert(lang.clone(), name.into());
+                added += 1;
+            }
+        }
+        added
+    }
+
+    /// The set `code` of `market`.
+    #[must_use]
+    pub fn get(&self, market: Market, code: &str) -> Option<&Set<'c>> {
+        self.index.get(&(market, code)).map(|&i| &self.sets[i])
+    }
+
+    /// The set `card` is printed in.
+    #[must_use]
+    pub fn for_card(&self, card: &Card<'_>) -> Option<&Set<'c>> {
+        self.get(card.market(), card.set_icon())
+    }
+
+    /// Every set, in the order they were released.
+    pub fn iter(&self) -> impl Iterator<Item = &Set<'c>> {
+        self.sets.iter()
+    }
+}
+
+#[test]
+fn registry() {
+    use crate::fixture::Fixture;
+
+    let card = |set: &str, number: u16, total: u16, reldate: &str| {
+        Fixture::item()
+            .id(&alloc::format!("{set}_{number}"))
+            .number(&alloc::format!("{number}/{total}"))
+            .reldate(reldate)
+            .card()
+    };
+
+    let cards = [
+        card("PAL", 181, 193, "2023-06-09"),
+        card("SVI", 181, 198, "2023-03-31"),
+        card("SVI", 244, 198, "2023-03-31"),
+        card("BRS", 132, 172, "2022-02-25"),
+    ];
+    let mut sets = Sets::new(&cards);
+    assert!(sets.add_name("SVI", Lang::FrFr, "Écarlate et Violet"));
+    assert!(!sets.add_name("SVI", Lang::JaJp, "スカーレットex"));
+
+    assert_eq!(
+        sets.iter().map(Set::code).collect::<Vec<_>>(),
+        ["BRS", "SVI", "PAL"]
+    );
+
+    let svi = sets.for_card(&cards[1]).unwrap();
+    assert_eq!(svi.total(), Some(198));
+    assert_eq!(svi.secret_rares(), 1);
+    assert_eq!(svi.series(), Series::ScarletViolet);
+    assert_eq!(svi.name(&Lang::FrFr), Some("Écarlate et Violet"));
+    assert_eq!(
+        sets.get(Market::International, "BRS").unwrap().series(),
+        Series::SwordShield
+    );
+}
+
+#[test]
+fn exports() {
+    use crate::fixture;
+
+    for (file_name, json) in fixture::exports() {
+        let cards = serde_json::from_str::<Vec<Card>>(&json).unwrap();
+        let sets = Sets::new(&cards);
+
+        for card in &cards {
+            let set = sets
+                .for_card(card)
+                .unwrap_or_else(|| panic!("{file_name}: no set for {}", card.tcgl().card_id()));
+            assert_eq!(set.code(), card.set_icon(), "{file_name}");
+            assert!(set.reldate() <= card.reldate(), "{file_name}");
+        }
+        assert_eq!(
+            sets.iter().map(Set::secret_rares).sum::<usize>(),
+            cards
+                .iter()
+                .filter(|card| card.collector_number().parts().section() == Section::Secret)
+                .map(|card| card.tcgl().card_id())
+                .collect::<BTreeSet<_>>()
+                .len(),
+            "{file_name}"
+        );
+    }
+}
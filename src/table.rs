@@ -0,0 +1,311 @@
+//! Flat, spreadsheet-friendly CSV/TSV export of cards.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Write};
+
+use crate::{Attack, Card, serde_name};
+
+/// Separator used between multiple values within a single cell, i.e. the
+/// `types` of a card or the `cost` of an attack.
+pub const LIST_SEPARATOR: &str = "|";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Tsv,
+}
+
+impl Format {
+    #[must_use]
+    pub const fn delimiter(self) -> char {
+        match self {
+            Format::Csv => ',',
+            Format::Tsv => '\t',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rows {
+    /// One row per card.
+    #[default]
+    Card,
+    /// One row per attack. Cards without any attacks still produce a single
+    /// row, with all of the attack columns left empty.
+    Attack,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Name,
+    Lang,
+    /// The card's `set_icon`.
+    Set,
+    /// `collector_number.full`.
+    CollectorNumber,
+    CardId,
+    Rarity,
+    FoilType,
+    FoilMask,
+    RegulationMark,
+    Hp,
+    Types,
+    /// Only populated with [`Rows::Attack`].
+    AttackName,
+    /// Only populated with [`Rows::Attack`].
+    AttackCost,
+    /// Only populated with [`Rows::Attack`].
+    AttackDamage,
+}
+
+impl Column {
+    #[must_use]
+    pub const fn header(self) -> &'static str {
+        match self {
+            Column::Name => "name",
+            Column::Lang => "lang",
+            Column::Set => "set",
+            Column::CollectorNumber => "collector_number",
+            Column::CardId => "card_id",
+            Column::Rarity => "rarity",
+            Column::FoilType => "foil_type",
+            Column::FoilMask => "foil_mask",
+            Column::RegulationMark => "regulation_mark",
+            Column::Hp => "hp",
+            Column::Types => "types",
+            Column::AttackName => "attack_name",
+            Column::AttackCost => "attack_cost",
+            Column::AttackDamage => "attack_damage",
+        }
+    }
+
+    fn write_value(self, out: &mut String, card: &Card<'_>, attack: Option<&Attack<'_>>) {
+        // writing to a `String` is infallible
+        let _ = match self {
+            Column::Name => out.write_str(card.name()),
+            Column::Lang => out.write_str(serde_name::variant(card.lang())),
+            Column::Set => out.write_str(card.set_icon()),
+            Column::CollectorNumber => out.write_str(&card.collector_number().full),
+            Column::CardId => out.write_str(card.tcgl().card_id.as_str()),
+            Column::Rarity => card.rarity().map_or(Ok(()), |rarity| {
+                out.write_str(serde_name::variant(&rarity.designation))
+            }),
+            Column::FoilType => card
+                .foil()
+                .map_or(Ok(()), |foil| out.write_str(serde_name::variant(&foil.ty))),
+            Column::FoilMask => card.foil().map_or(Ok(()), |foil| {
+                out.write_str(serde_name::variant(&foil.mask))
+            }),
+            Column::RegulationMark => card
+                .regulation_mark()
+                .map_or(Ok(()), |mark| out.write_str(serde_name::variant(mark))),
+            Column::Hp => card.hp().map_or(Ok(()), |hp| write!(out, "{hp}")),
+            Column::Types => write_list(out, card.types()),
+            Column::AttackName => attack.map_or(Ok(()), |attack| out.write_str(&attack.name)),
+            Column::AttackCost => attack.map_or(Ok(()), |attack| write_list(out, &attack.cost)),
+            Column::AttackDamage => {
+                attack
+                    .and_then(|attack| attack.damage.as_ref())
+                    .map_or(Ok(()), |damage| {
+                        write!(out, "{}", damage.amount)?;
+                        damage
+                            .suffix
+                            .as_ref()
+                            .map_or(Ok(()), |suffix| out.write_str(serde_name::variant(suffix)))
+                    })
+            }
+        };
+    }
+}
+
+fn write_list<T: serde::Serialize>(out: &mut String, values: &[T]) -> fmt::Result {
+    for (i, value) in values.iter().enumerate() {
+        if i != 0 {
+            out.write_str(LIST_SEPARATOR)?;
+        }
+        out.write_str(serde_name::variant(value))?;
+    }
+    Ok(())
+}
+
+/// A configurable flattening of [`Card`]s into delimited rows.
+///
+/// ```
+/// use malie::table::{Column, Format, Rows, Table};
+///
+/// let table = Table::new(Format::Csv, [Column::Name, Column::AttackName, Column::AttackDamage])
+///     .rows(Rows::Attack);
+///
+/// let mut csv = String::new();
+/// table.write(&mut csv, &[]).unwrap();
+///
+/// assert_eq!(csv, "name,attack_name,attack_damage\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    format: Format,
+    rows: Rows,
+    columns: Vec<Column>,
+    header: bool,
+}
+
+impl Table {
+    #[must_use]
+    pub fn new(format: Format, columns: impl IntoIterator<Item = Column>) -> Self {
+        Self {
+            format,
+            rows: Rows::default(),
+            columns: columns.into_iter().collect(),
+            header: true,
+        }
+    }
+
+    #[must_use]
+    pub fn rows(mut self, rows: Rows) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Whether to write a header row. Defaults to `true`.
+    #[must_use]
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Write `cards` as rows to `out`, with each row terminated by `\n`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `out` fails.
+    pub fn write<W: Write>(&self, out: &mut W, cards: &[Card<'_>]) -> fmt::Result {
+        let mut cell = String::new();
+
+        if self.header {
+            self.write_row(out, &mut cell, |cell, column| {
+                cell.push_str(column.header());
+            })?;
+        }
+
+        for card in cards {
+            match self.rows {
+                Rows::Card => {
+                    self.write_row(out, &mut cell, |cell, column| {
+                        column.write_value(cell, card, None);
+                    })?;
+                }
+                Rows::Attack => {
+                    let mut attacks = card.attacks().peekable();
+
+                    if attacks.peek().is_none() {
+                        self.write_row(out, &mut cell, |cell, column| {
+                            column.write_value(cell, card, None);
+                        })?;
+                    }
+
+                    for attack in attacks {
+                        self.write_row(out, &mut cell, |cell, column| {
+                            column.write_value(cell, card, Some(attack));
+                        })?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_row<W: Write>(
+        &self,
+        out: &mut W,
+        cell: &mut String,
+        mut f: impl FnMut(&mut String, Column),
+    ) -> fmt::Result {
+        for (i, column) in self.columns.iter().enumerate() {
+            if i != 0 {
+                out.write_char(self.format.delimiter())?;
+            }
+
+            cell.clear();
+            f(cell, *column);
+            write_cell(out, cell, self.format.delimiter())?;
+        }
+
+        out.write_char('\n')
+    }
+}
+
+/// Write a single cell, quoting it (RFC 4180 style) if it contains the
+/// delimiter, a quote, or a line break. Spreadsheets accept this quoting for
+/// TSV as well.
+pub(crate) fn write_cell<W: Write>(out: &mut W, cell: &str, delimiter: char) -> fmt::Result {
+    if cell.contains([delimiter, '"', '\n', '\r']) {
+        out.write_char('"')?;
+        for (i, part) in cell.split('"').enumerate() {
+            if i != 0 {
+                out.write_str("\"\"")?;
+            }
+            out.write_str(part)?;
+        }
+        out.write_char('"')
+    } else {
+        out.write_str(cell)
+    }
+}
+
+#[test]
+fn per_attack_rows() {
+    use serde_json::json;
+
+    use crate::fixture::Fixture;
+
+    let cards = [Fixture::pokemon()
+        .id("SVI_244")
+        .number("244/198")
+        .with("foil", json!({"type": "SV_ULTRA", "mask": "HOLO"}))
+        .with(
+            "rarity",
+            json!({"designation": "SPECIAL_ILLUSTRATION_RARE", "icon": "TWO_GOLD_STARS"}),
+        )
+        .with(
+            "text",
+            json!([
+                {"kind": "ATTACK", "name": "Zap, Zap", "cost": ["LIGHTNING", "COLORLESS"], "damage": {"amount": 20, "suffix": "+"}},
+                {"kind": "ATTACK", "name": "Photon \"Blaster\"", "cost": ["FREE"]}
+            ]),
+        )
+        .card()];
+
+    let table = Table::new(
+        Format::Csv,
+        [
+            Column::Name,
+            Column::CollectorNumber,
+            Column::Rarity,
+            Column::FoilMask,
+            Column::Hp,
+            Column::AttackName,
+            Column::AttackCost,
+            Column::AttackDamage,
+        ],
+    )
+    .rows(Rows::Attack);
+
+    let mut csv = String::new();
+    table.write(&mut csv, &cards).unwrap();
+
+    assert_eq!(
+        csv,
+        "name,collector_number,rarity,foil_mask,hp,attack_name,attack_cost,attack_damage\n\
+         Miraidon ex,244/198,SPECIAL_ILLUSTRATION_RARE,HOLO,220,\"Zap, Zap\",LIGHTNING|COLORLESS,20+\n\
+         Miraidon ex,244/198,SPECIAL_ILLUSTRATION_RARE,HOLO,220,\"Photon \"\"Blaster\"\"\",FREE,\n"
+    );
+
+    let mut tsv = String::new();
+    Table::new(Format::Tsv, [Column::Name, Column::Types])
+        .header(false)
+        .write(&mut tsv, &cards)
+        .unwrap();
+
+    assert_eq!(tsv, "Miraidon ex\tLIGHTNING\n");
+}
@@ -0,0 +1,222 @@
+//! TypeScript declarations for the [`Card`] model.
+//!
+//! The declarations are generated from the same derived schema as
+//! [`json_schema`](crate::json_schema), but for what the model serializes
+//! rather than what it accepts: fields that are skipped when `None` are
+//! optional (`name?: T`) rather than nullable.
+//!
+//! Enable the `typescript` feature to use this module.
+
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use serde_json::{Map, Value};
+
+use crate::{Card, json_schema};
+
+/// Generate a `.d.ts` module declaring every type in the model, plus `Export`
+/// for the contents of an entire export file.
+///
+/// ```
+/// let declarations = malie::typescript::declarations();
+///
+/// assert!(declarations.contains("export type Export = Card[];"));
+/// ```
+#[must_use]
+pub fn declarations() -> String {
+    let schema = json_schema::settings()
+        .for_serialize()
+        .into_generator()
+        .into_root_schema_for::<Vec<Card<'static>>>();
+
+    let mut out = String::new();
+
+    for (name, def) in schema
+        .get("$defs")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+    {
+        if let Some(description) = def.get("description").and_then(Value::as_str) {
+            let _ = writeln!(out, "/** {description} */");
+        }
+
+        let declaration = ty(def, 0);
+        if declaration.starts_with('{') {
+            let _ = writeln!(out, "export interface {name} {declaration}\n");
+        } else if declaration.starts_with('\n') {
+            let _ = writeln!(out, "export type {name} ={declaration};\n");
+        } else {
+            let _ = writeln!(out, "export type {name} = {declaration};\n");
+        }
+    }
+
+    let _ = writeln!(out, "export type Export = {};", ty(schema.as_value(), 0));
+
+    out
+}
+
+fn ty(schema: &Value, indent: usize) -> String {
+    let Value::Object(schema) = schema else {
+        return if schema == &Value::Bool(false) {
+            "never"
+        } else {
+            "unknown"
+        }
+        .to_owned();
+    };
+
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return reference.trim_start_matches("#/$defs/").to_owned();
+    }
+
+    if let Some(value) = schema.get("const") {
+        return value.to_string();
+    }
+
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        return union(values.iter().map(Value::to_string), indent);
+    }
+
+    let variants = schema
+        .get("oneOf")
+        .or_else(|| schema.get("anyOf"))
+        .and_then(Value::as_array)
+        .map(|variants| union(variants.iter().map(|v| ty(v, indent + 1)), indent));
+
+    let base = match schema.get("type") {
+        Some(Value::String(name)) => Some(primitive(schema, name, indent)),
+        Some(Value::Array(names)) => Some(union(
+            names
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|name| primitive(schema, name, indent)),
+            indent,
+        )),
+        _ => None,
+    };
+
+    match (base, variants) {
+        (Some(base), Some(variants)) => format!("{base} & ({variants})"),
+        (Some(ty), None) | (None, Some(ty)) => ty,
+        (None, None) => "unknown".to_owned(),
+    }
+}
+
+fn primitive(schema: &Map<String, Value>, name: &str, indent: usize) -> String {
+    match name {
+        "object" => object(schema, indent),
+        "array" => {
+            let items = schema
+                .get("items")
+                .map_or_else(|| "unknown".to_owned(), |items| ty(items, indent));
+            if items.contains(' ') {
+                format!("Array<{items}>")
+            } else {
+                format!("{items}[]")
+            }
+        }
+        "integer" | "number" => "number".to_owned(),
+        other => other.to_owned(),
+    }
+}
+
+fn object(schema: &Map<String, Value>, indent: usize) -> String {
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        return "Record<string, unknown>".to_owned();
+    };
+
+    let required = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect::<Vec<_>>();
+
+    let pad = "  ".repeat(indent);
+    let mut out = String::from("{\n");
+
+    for (name, property) in properties {
+        let key = if is_identifier(name) {
+            name.clone()
+        } else {
+            Value::from(name.as_str()).to_string()
+        };
+
+        let _ = if required.contains(&name.as_str()) {
+            writeln!(out, "{pad}  {key}: {};", ty(property, indent + 1))
+        } else {
+            // optional properties are only ever omitted, never null
+            writeln!(
+                out,
+                "{pad}  {key}?: {};",
+                ty(&without_null(property), indent + 1)
+            )
+        };
+    }
+
+    out.push_str(&pad);
+    out.push('}');
+    out
+}
+
+fn union(members: impl Iterator<Item = String>, indent: usize) -> String {
+    let members = members.collect::<Vec<_>>();
+
+    if members.iter().any(|member| member.contains('\n')) {
+        let pad = "  ".repeat(indent + 1);
+        members.iter().fold(String::new(), |mut out, member| {
+            let _ = write!(out, "\n{pad}| {member}");
+            out
+        })
+    } else {
+        members.join(" | ")
+    }
+}
+
+fn without_null(schema: &Value) -> Value {
+    let is_null = |v: &Value| v == "null" || v.get("type").is_some_and(|ty| ty == "null");
+
+    let mut schema = schema.clone();
+
+    if let Some(Value::Array(types)) = schema.get_mut("type") {
+        types.retain(|ty| !is_null(ty));
+        if let [ty] = types.as_slice() {
+            schema["type"] = ty.clone();
+        }
+    }
+
+    if let Some(Value::Array(variants)) = schema.get_mut("anyOf") {
+        variants.retain(|variant| !is_null(variant));
+        if let [variant] = variants.as_slice() {
+            return variant.clone();
+        }
+    }
+
+    schema
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+#[test]
+fn optional_fields() {
+    let declarations = declarations();
+
+    assert!(
+        declarations.contains("export interface Foil {\n  mask: FoilMask;\n  type: FoilType;\n}")
+    );
+    assert!(declarations.contains("    subtitle?: string;\n"));
+    assert!(!declarations.contains("null"));
+}
@@ -0,0 +1,272 @@
+//! Semantic validation of cards, beyond the shape checks done while
+//! deserializing.
+
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+
+use crate::{Card, CardTag, CollectorNumber, FoilMask, FoilType, Text, serde_name};
+
+/// The first year a Pokémon TCG card was printed.
+const FIRST_PRINT_YEAR: u16 = 1996;
+
+/// A cross-field invariant that a card does not uphold.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Diagnostic {
+    /// `collector_number.full` is not `numerator`, or
+    /// `numerator/denominator` when there is a denominator.
+    CollectorNumberFull { full: String, expected: String },
+    /// `collector_number.numeric` is not the number in
+    /// `collector_number.numerator`.
+    CollectorNumberNumeric { numeric: u16, expected: u16 },
+    /// An attack deals an amount of damage that is not a multiple of 10.
+    DamageAmount { attack: String, amount: u16 },
+    /// `copyright.year` is after the year the card was released, or before
+    /// the first card was ever printed.
+    CopyrightYear { year: u16, reldate_year: i32 },
+    /// The foil type can not be printed with the foil mask.
+    Foil { ty: FoilType, mask: FoilMask },
+    /// The card has a tag with a rule, i.e. [`CardTag::ExLower`] or
+    /// [`CardTag::PrismStar`], but has no rule box.
+    MissingRuleBox(CardTag),
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnostic::CollectorNumberFull { full, expected } => {
+                write!(f, "collector number {full:?} should be {expected:?}")
+            }
+            Diagnostic::CollectorNumberNumeric { numeric, expected } => {
+                write!(f, "numeric collector number {numeric} should be {expected}")
+            }
+            Diagnostic::DamageAmount { attack, amount } => write!(
+                f,
+                "attack {attack:?} deals {amount} damage, which is not a multiple of 10"
+            ),
+            Diagnostic::CopyrightYear { year, reldate_year } => write!(
+                f,
+                "copyright year {year} is implausible for a card released in {reldate_year}"
+            ),
+            Diagnostic::Foil { ty, mask } => {
+                write!(
+                    f,
+                    "foil type {} can not be printed with mask {}",
+                    serde_name::variant(ty),
+                    serde_name::variant(mask)
+                )
+            }
+            Diagnostic::MissingRuleBox(tag) => write!(
+                f,
+                "card is tagged {} but has no rule box",
+                serde_name::variant(tag)
+            ),
+        }
+    }
+}
+
+impl Card<'_> {
+    /// Check the invariants between fields of the card that deserialization
+    /// does not, returning a diagnostic for each one that does not hold.
+    #[must_use]
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        collector_number(self.collector_number(), &mut diagnostics);
+
+        for attack in self.attacks() {
+            if let Some(damage) = &attack.damage
+                && damage.amount % 10 != 0
+            {
+                diagnostics.push(Diagnostic::DamageAmount {
+                    attack: attack.name.clone().into_owned(),
+                    amount: damage.amount,
+                });
+            }
+        }
+
+        if let Some(copyright) = self.copyright() {
+            let reldate_year = self.tcgl().reldate.year();
+            if i32::from(copyright.year) > reldate_year || copyright.year < FIRST_PRINT_YEAR {
+                diagnostics.push(Diagnostic::CopyrightYear {
+                    year: copyright.year,
+                    reldate_year,
+                });
+            }
+        }
+
+        if let Some(foil) = self.foil()
+            && !foil_is_valid(&foil.ty, &foil.mask)
+        {
+            diagnostics.push(Diagnostic::Foil {
+                ty: foil.ty.clone(),
+                mask: foil.mask.clone(),
+            });
+        }
+
+        if let Some(tag) = self.tags().iter().find(|tag| has_rule(tag))
+            && !self
+                .text()
+                .iter()
+                .any(|text| matches!(text, Text::RuleBox(_)))
+        {
+            diagnostics.push(Diagnostic::MissingRuleBox(tag.clone()));
+        }
+
+        diagnostics
+    }
+}
+
+fn collector_number(number: &CollectorNumber<'_>, diagnostics: &mut Vec<Diagnostic>) {
+    let expected = match &number.denominator {
+        Some(denominator) => format!("{}/{denominator}", number.numerator),
+        None => number.numerator.clone().into_owned(),
+    };
+    if number.full != expected {
+        diagnostics.push(Diagnostic::CollectorNumberFull {
+            full: number.full.clone().into_owned(),
+            expected,
+        });
+    }
+
+    // numerators without any digits, i.e. for some promos, have nothing to
+    // compare against
+    if let Some(expected) = number.parts().number()
+        && number.numeric.get() != expected
+    {
+        diagnostics.push(Diagnostic::CollectorNumberNumeric {
+            numeric: number.numeric.get(),
+            expected,
+        });
+    }
+}
+
+/// Whether cards with `tag` have a rule box explaining the tag, i.e. the
+/// extra prize cards taken when a Pokémon ex is knocked out.
+fn has_rule(tag: &CardTag) -> bool {
+    matches!(
+        tag,
+        CardTag::ExLower
+            | CardTag::ExUpper
+            | CardTag::Break
+            | CardTag::Gx
+            | CardTag::TagTeam
+            | CardTag::PrismStar
+            | CardTag::V
+            | CardTag::Vmax
+            | CardTag::Vstar
+            | CardTag::VUnion
+            | CardTag::Radiant
+    )
+}
+
+/// Whether a foil of type `ty` can be printed with `mask`. Only combinations
+/// that are known to be impossible are rejected.
+fn foil_is_valid(ty: &FoilType, mask: &FoilMask) -> bool {
+    match (ty, mask) {
+        // stamps are only ever printed on their own
+        (FoilType::Stamped, mask) => *mask == FoilMask::Stamped,
+        (_, FoilMask::Stamped) => false,
+        // ultra rare foils cover the whole card, never just the reverse
+        (FoilType::SvUltra | FoilType::SvUltraScodix, mask) => {
+            !matches!(mask, FoilMask::Reverse | FoilMask::ReverseLaminate)
+        }
+        _ => true,
+    }
+}
+
+#[test]
+fn diagnostics() {
+    use serde_json::json;
+
+    use crate::fixture::Fixture;
+
+    let card = Fixture::pokemon()
+        .with("foil", json!({"type": "SV_ULTRA", "mask": "REVERSE"}))
+        .with(
+            "collector_number",
+            json!({"full": "081/198", "numerator": "081", "denominator": "198", "numeric": 18}),
+        )
+        .with("copyright", json!({"text": "©2024 Pokémon", "year": 2024}))
+        .with(
+            "text",
+            json!([
+                {"kind": "ATTACK", "name": "Photon Blaster", "cost": ["LIGHTNING"], "damage": {"amount": 225}}
+            ]),
+        )
+        .card();
+
+    assert_eq!(
+        card.validate(),
+        [
+            Diagnostic::CollectorNumberNumeric {
+                numeric: 18,
+                expected: 81
+            },
+            Diagnostic::DamageAmount {
+                attack: "Photon Blaster".into(),
+                amount: 225
+            },
+            Diagnostic::CopyrightYear {
+                year: 2024,
+                reldate_year: 2023
+            },
+            Diagnostic::Foil {
+                ty: FoilType::SvUltra,
+                mask: FoilMask::Reverse
+            },
+            Diagnostic::MissingRuleBox(CardTag::ExLower),
+        ]
+    );
+}
+
+#[test]
+fn edge_cases() {
+    use serde_json::json;
+
+    use crate::fixture::Fixture;
+
+    for fixture in [Fixture::item(), Fixture::pokemon(), Fixture::basic_energy()] {
+        assert_eq!(fixture.card().validate(), []);
+    }
+
+    // a promo without any digits in its numerator, and a copyright from the
+    // year of release
+    let promo = Fixture::item()
+        .with(
+            "collector_number",
+            json!({"full": "PROMO", "numerator": "PROMO", "numeric": 1}),
+        )
+        .with("copyright", json!({"text": "©2023 Pokémon", "year": 2023}))
+        .with("foil", json!({"type": "STAMPED", "mask": "STAMPED"}))
+        .card();
+    assert_eq!(promo.validate(), []);
+
+    let card = Fixture::pokemon()
+        .with(
+            "collector_number",
+            json!({"full": "81/198", "numerator": "081", "denominator": "198", "numeric": 81}),
+        )
+        .with("copyright", json!({"text": "©1995", "year": 1995}))
+        .with("foil", json!({"type": "STAMPED", "mask": "REVERSE"}))
+        .with("tags", json!(["RADIANT"]))
+        .with("text", json!([]))
+        .card();
+    assert_eq!(
+        card.validate(),
+        [
+            Diagnostic::CollectorNumberFull {
+                full: "81/198".into(),
+                expected: "081/198".into()
+            },
+            Diagnostic::CopyrightYear {
+                year: 1995,
+                reldate_year: 2023
+            },
+            Diagnostic::Foil {
+                ty: FoilType::Stamped,
+                mask: FoilMask::Reverse
+            },
+            Diagnostic::MissingRuleBox(CardTag::Radiant),
+        ]
+    );
+}
@@ -0,0 +1,201 @@
+//! A [`wasm-bindgen`] API for using the crate from JavaScript.
+//!
+//! Cards are returned as plain JS objects, in exactly the same shape as the
+//! export JSON. Decks are checked against the rules in [`deck`](crate::deck).
+//!
+//! Enable the `wasm` feature to use this module. The tests run under a
+//! headless wasm runtime with:
+//!
+//! ```sh
+//! wasm-pack test --node -- --features wasm
+//! ```
+//!
+//! [`wasm-bindgen`]: https://docs.rs/wasm-bindgen
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_wasm_bindgen::Serializer;
+use wasm_bindgen::prelude::*;
+
+use crate::{Card, EnergyType, Lang, RegulationMark, deck::Deck, id::CardId};
+
+const SERIALIZER: Serializer = Serializer::json_compatible();
+
+/// A parsed export file.
+#[wasm_bindgen]
+pub struct Export {
+    cards: Vec<Card<'static>>,
+}
+
+/// Parse the contents of an export file.
+///
+/// # Errors
+///
+/// Throws if `json` is not a valid export.
+#[wasm_bindgen(js_name = parseExport)]
+pub fn parse_export(json: &str) -> Result<Export, JsError> {
+    let cards = serde_json::from_str::<Vec<Card>>(json)?;
+
+    Ok(Export {
+        cards: cards.into_iter().map(Card::into_owned).collect(),
+    })
+}
+
+#[wasm_bindgen]
+impl Export {
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn length(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// The card at `index`, or `undefined` if it is out of bounds.
+    ///
+    /// # Errors
+    ///
+    /// Throws if the card could not be converted to a JS object.
+    pub fn card(&self, index: usize) -> Result<JsValue, JsError> {
+        match self.cards.get(index) {
+            Some(card) => Ok(card.serialize(&SERIALIZER)?),
+            None => Ok(JsValue::UNDEFINED),
+        }
+    }
+
+    /// All of the cards in the export.
+    ///
+    /// # Errors
+    ///
+    /// Throws if the cards could not be converted to JS objects.
+    pub fn cards(&self) -> Result<JsValue, JsError> {
+        Ok(self.cards.serialize(&SERIALIZER)?)
+    }
+
+    /// The `cardID` of every card in the export, in order.
+    #[wasm_bindgen(js_name = cardIds)]
+    #[must_use]
+    pub fn card_ids(&self) -> Vec<String> {
+        self.cards
+            .iter()
+            .map(|card| card.tcgl().card_id.as_str().into())
+            .collect()
+    }
+
+    /// All of the cards matching `query`, an object with any of the
+    /// following optional properties:
+    ///
+    /// - `name`: a case-insensitive substring of the card's name
+    /// - `lang`: i.e. `"en-US"`
+    /// - `set`: the card's `set_icon`
+    /// - `regulationMark`: i.e. `"G"`
+    /// - `type`: an energy type the card has, i.e. `"GRASS"`
+    ///
+    /// # Errors
+    ///
+    /// Throws if `query` is not a valid query, or if the cards could not be
+    /// converted to JS objects.
+    pub fn query(&self, query: JsValue) -> Result<JsValue, JsError> {
+        let query = if query.is_undefined() {
+            Query::default()
+        } else {
+            serde_wasm_bindgen::from_value::<Query>(query)?
+        };
+
+        Ok(self
+            .cards
+            .iter()
+            .filter(|card| query.matches(card))
+            .collect::<Vec<_>>()
+            .serialize(&SERIALIZER)?)
+    }
+
+    /// Check `deck`, an object of the number of copies of each `cardID`, i.e.
+    /// `{"SVI_181": 4}`, against the deck construction rules using the cards
+    /// in the export. Returns a message for each rule the deck breaks, so an
+    /// empty array means the deck is legal.
+    ///
+    /// # Errors
+    ///
+    /// Throws if `deck` is not a valid deck.
+    #[wasm_bindgen(js_name = validateDeck)]
+    pub fn validate_deck(&self, deck: JsValue) -> Result<Vec<String>, JsError> {
+        let deck = serde_wasm_bindgen::from_value::<BTreeMap<String, u32>>(deck)?
+            .into_iter()
+            .map(|(card_id, count)| (CardId::new(card_id), count))
+            .collect::<Deck>();
+
+        Ok(deck
+            .validate(&self.cards)
+            .iter()
+            .map(ToString::to_string)
+            .collect())
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "camelCase")]
+struct Query {
+    name: Option<String>,
+    lang: Option<Lang>,
+    set: Option<String>,
+    regulation_mark: Option<RegulationMark>,
+    #[serde(rename = "type")]
+    ty: Option<EnergyType>,
+}
+
+impl Query {
+    fn matches(&self, card: &Card<'_>) -> bool {
+        self.name
+            .as_ref()
+            .is_none_or(|name| card.name().to_lowercase().contains(&name.to_lowercase()))
+            && self.lang.as_ref().is_none_or(|lang| card.lang() == lang)
+            && self.set.as_ref().is_none_or(|set| card.set_icon() == set)
+            && self
+                .regulation_mark
+                .as_ref()
+                .is_none_or(|mark| card.regulation_mark() == Some(mark))
+            && self.ty.as_ref().is_none_or(|ty| card.types().contains(ty))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen_test::wasm_bindgen_test]
+fn parse_and_query() {
+    let export = parse_export(&crate::fixture::export([
+        &crate::fixture::Fixture::basic_energy(),
+    ]))
+    .unwrap();
+
+    assert_eq!(export.length(), 1);
+    assert_eq!(export.card_ids(), ["SVE_1"]);
+
+    let card = export.card(0).unwrap();
+    assert_eq!(
+        js_sys::Reflect::get(&card, &"name".into()).unwrap(),
+        "Basic Grass Energy"
+    );
+    assert!(export.card(1).unwrap().is_undefined());
+
+    let query = |query: &str| {
+        js_sys::Array::from(&export.query(js_sys::JSON::parse(query).unwrap()).unwrap()).length()
+    };
+
+    assert_eq!(query(r#"{"name": "grass", "type": "GRASS"}"#), 1);
+    assert_eq!(query(r#"{"set": "SVI"}"#), 0);
+
+    let validate_deck = |deck: &str| {
+        export
+            .validate_deck(js_sys::JSON::parse(deck).unwrap())
+            .unwrap()
+    };
+
+    assert!(validate_deck(r#"{"SVE_1": 60}"#).is_empty());
+    assert_eq!(
+        validate_deck(r#"{"SVE_1": 59, "SVI_181": 1}"#),
+        ["unknown card SVI_181"]
+    );
+}